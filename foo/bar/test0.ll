@@ -0,0 +1,5 @@
+declare void @printInt(i32)
+define i32 @main() {
+entry:
+	ret i32 0
+}
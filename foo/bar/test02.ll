@@ -0,0 +1,7 @@
+declare void @printInt(i32)
+define i32 @main() {
+entry:
+	%r1 = sub i32 44, 2
+	call void @printInt(i32 %r1)
+	ret i32 0
+}
@@ -0,0 +1,50 @@
+declare void @printInt(i32)
+define i32 @main() {
+entry:
+	%loc_a = alloca i32
+	store i32 1, i32* %loc_a
+	%loc_b = alloca i32
+	store i32 2, i32* %loc_b
+	%b0 = load i32, i32* %loc_b
+	%a0 = load i32, i32* %loc_a
+	%r1 = add i32 %b0, %a0
+	%r2 = add i32 %r1, %a0
+	%r3 = add i32 %r2, 1
+	%r4 = add i32 %r3, %a0
+	%r5 = add i32 %r4, %a0
+	%r6 = add i32 %r5, %a0
+	%r7 = add i32 %r6, %a0
+	%r8 = add i32 %r7, %a0
+	%r9 = add i32 %r8, 1
+	%r10 = add i32 %r9, %a0
+	%r11 = add i32 %r10, %a0
+	%r12 = add i32 %r11, %a0
+	%r13 = add i32 %r12, %b0
+	%r14 = add i32 %r13, 1
+	%r15 = add i32 %r14, %a0
+	%r16 = add i32 %r15, %a0
+	%r17 = add i32 %r16, %a0
+	%r18 = add i32 %r17, %a0
+	%r19 = add i32 %r18, %a0
+	%r20 = add i32 %r19, %a0
+	%r21 = add i32 %r20, %a0
+	%r22 = add i32 %r21, %a0
+	%r23 = add i32 %r22, %a0
+	%r24 = add i32 %r23, %a0
+	%r25 = add i32 %r24, 1
+	%r26 = add i32 %r25, %a0
+	%r27 = add i32 %r26, %a0
+	%r28 = add i32 %r27, %a0
+	%r29 = add i32 %r28, %a0
+	%r30 = add i32 %r29, 1
+	%r31 = add i32 %r30, %a0
+	%r32 = add i32 %r31, %a0
+	%r33 = add i32 %r32, 1
+	%r34 = add i32 %r33, %a0
+	%r35 = add i32 %r34, %a0
+	%r36 = add i32 %r35, 1
+	%r37 = add i32 %r36, %a0
+	%r38 = add i32 %r37, %b0
+	call void @printInt(i32 %r38)
+	ret i32 0
+}
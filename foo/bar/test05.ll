@@ -0,0 +1,44 @@
+declare void @printInt(i32)
+define i32 @main() {
+entry:
+	%r1 = add i32 1, 1
+	%r2 = sub i32 %r1, 1
+	%r3 = add i32 %r2, 1
+	%r4 = sub i32 %r3, 1
+	%r5 = add i32 %r4, 1
+	%r6 = sub i32 %r5, 1
+	%r7 = add i32 %r6, 1
+	%r8 = sub i32 %r7, 1
+	%r9 = add i32 %r8, 1
+	%r10 = sub i32 %r9, 1
+	%r11 = add i32 %r10, 1
+	%r12 = sub i32 %r11, 1
+	%r13 = add i32 %r12, 1
+	%r14 = sub i32 %r13, 1
+	%r15 = add i32 %r14, 1
+	%r16 = sub i32 %r15, 1
+	%r17 = add i32 %r16, 1
+	%r18 = sub i32 %r17, 1
+	%r19 = add i32 %r18, 1
+	%r20 = sub i32 %r19, 1
+	%r21 = add i32 %r20, 1
+	%r22 = sub i32 %r21, 1
+	%r23 = add i32 %r22, 1
+	%r24 = sub i32 %r23, 1
+	%r25 = add i32 %r24, 1
+	%r26 = sub i32 %r25, 1
+	%r27 = add i32 %r26, 1
+	%r28 = sub i32 %r27, 1
+	%r29 = add i32 %r28, 1
+	%r30 = sub i32 %r29, 1
+	%r31 = add i32 %r30, 1
+	%r32 = sub i32 %r31, 1
+	%r33 = add i32 %r32, 1
+	%r34 = sub i32 %r33, 1
+	%r35 = add i32 %r34, 1
+	%r36 = sub i32 %r35, 1
+	%r37 = add i32 %r36, 1
+	%r38 = sub i32 %r37, 1
+	call void @printInt(i32 %r38)
+	ret i32 0
+}
@@ -1,5 +1,6 @@
 declare void @printInt(i32)
 define i32 @main() {
+entry:
 	%loc_a = alloca i32
 	store i32 0, i32* %loc_a
 	%loc_b = alloca i32
@@ -85,4 +86,4 @@ define i32 @main() {
 	%r24 = sdiv i32 %r23, 10
 	call void @printInt(i32 %r24)
 	ret i32 0
-}
\ No newline at end of file
+}
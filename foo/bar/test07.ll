@@ -0,0 +1,38 @@
+declare void @printInt(i32)
+define i32 @main() {
+entry:
+	%loc_a = alloca i32
+	store i32 7, i32* %loc_a
+	%loc_b = alloca i32
+	store i32 5, i32* %loc_b
+	%a0 = load i32, i32* %loc_a
+	%b0 = load i32, i32* %loc_b
+	%r1 = mul i32 %a0, %b0
+	%r2 = add i32 %r1, 3
+	%r3 = mul i32 %r2, %r2
+	%r4 = sub i32 %r3, %r1
+	%loc_c = alloca i32
+	store i32 %r4, i32* %loc_c
+	%r5 = sub i32 %r1, 30
+	%r6 = icmp eq i32 %r5, -1
+	%r7 = select i1 %r6, i32 1, i32 %r5
+	%r8 = sdiv i32 %r2, %r7
+	%r9 = sub i32 0, %r8
+	%r10 = select i1 %r6, i32 %r9, i32 %r8
+	%loc_d = alloca i32
+	store i32 %r10, i32* %loc_d
+	%r11 = sub i32 %a0, 1
+	store i32 %r11, i32* %loc_a
+	%a1 = load i32, i32* %loc_a
+	%r12 = mul i32 %a1, %b0
+	%r13 = add i32 %r12, 3
+	%r14 = mul i32 %r13, %r13
+	call void @printInt(i32 %r14)
+	%c0 = load i32, i32* %loc_c
+	call void @printInt(i32 %c0)
+	%d0 = load i32, i32* %loc_d
+	call void @printInt(i32 %d0)
+	%r15 = sub i32 %r13, %r13
+	call void @printInt(i32 %r15)
+	ret i32 0
+}
@@ -0,0 +1,48 @@
+declare void @printInt(i32)
+define i32 @main() {
+entry:
+	%loc_r = alloca i32
+	store i32 1, i32* %loc_r
+	%r0 = load i32, i32* %loc_r
+	%r1 = add i32 %r0, 2
+	%loc_r1 = alloca i32
+	store i32 %r1, i32* %loc_r1
+	%r10 = load i32, i32* %loc_r1
+	%r2 = mul i32 %r10, 3
+	%loc_loc_r = alloca i32
+	store i32 %r2, i32* %loc_loc_r
+	%loc_r0 = load i32, i32* %loc_loc_r
+	%r3 = sub i32 %loc_r0, %r0
+	%loc_var__r = alloca i32
+	store i32 %r3, i32* %loc_var__r
+	%var__r0 = load i32, i32* %loc_var__r
+	%r4 = add i32 %var__r0, %r10
+	%loc_r0.1 = alloca i32
+	store i32 %r4, i32* %loc_r0.1
+	%r00 = load i32, i32* %loc_r0.1
+	%r5 = mul i32 %r00, %r00
+	%loc_entry = alloca i32
+	store i32 %r5, i32* %loc_entry
+	%entry0 = load i32, i32* %loc_entry
+	%r6 = add i32 %entry0, 1
+	%loc_printInt = alloca i32
+	store i32 %r6, i32* %loc_printInt
+	%printInt0 = load i32, i32* %loc_printInt
+	%r7 = sub i32 %printInt0, %r00
+	%"loc_za\C5\BC\C3\B3\C5\82\C4\87" = alloca i32
+	store i32 %r7, i32* %"loc_za\C5\BC\C3\B3\C5\82\C4\87"
+	%r8 = add i32 %r10, %r0
+	call void @printInt(i32 %r8)
+	call void @printInt(i32 %loc_r0)
+	%r9 = mul i32 %var__r0, %r00
+	call void @printInt(i32 %r9)
+	call void @printInt(i32 %entry0)
+	call void @printInt(i32 %printInt0)
+	%"za\C5\BC\C3\B3\C5\82\C4\870" = load i32, i32* %"loc_za\C5\BC\C3\B3\C5\82\C4\87"
+	call void @printInt(i32 %"za\C5\BC\C3\B3\C5\82\C4\870")
+	store i32 %r10, i32* %loc_r
+	%r1.1 = load i32, i32* %loc_r
+	%r10.1 = mul i32 %r1.1, %r1.1
+	store i32 %r10.1, i32* %loc_r1
+	ret i32 0
+}
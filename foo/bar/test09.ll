@@ -0,0 +1,39 @@
+declare void @printInt(i32)
+define i32 @main() {
+entry:
+	%loc_a = alloca i32
+	store i32 -5, i32* %loc_a
+	%a0 = load i32, i32* %loc_a
+	%r1 = add i32 %a0, 3
+	%r2 = sub i32 0, %r1
+	%r3 = mul i32 %r2, -2
+	%loc_b = alloca i32
+	store i32 %r3, i32* %loc_b
+	%r4 = sub i32 0, %a0
+	%r5 = sub i32 0, %r4
+	%loc_c = alloca i32
+	store i32 %r5, i32* %loc_c
+	%loc_d = alloca i32
+	store i32 -2147483648, i32* %loc_d
+	call void @printInt(i32 %r4)
+	%b0 = load i32, i32* %loc_b
+	call void @printInt(i32 %b0)
+	%c0 = load i32, i32* %loc_c
+	%r6 = sub i32 %c0, -1
+	call void @printInt(i32 %r6)
+	%d0 = load i32, i32* %loc_d
+	%r7 = sub i32 0, %d0
+	call void @printInt(i32 %r7)
+	%r8 = sub i32 0, -1
+	call void @printInt(i32 %r8)
+	%r9 = sub i32 %a0, -2
+	store i32 %r9, i32* %loc_a
+	%a1 = load i32, i32* %loc_a
+	call void @printInt(i32 %a1)
+	%r10 = sub i32 0, %a1
+	%r11 = sub i32 0, %r10
+	%r12 = sub i32 0, %r11
+	%r13 = mul i32 %b0, %r12
+	call void @printInt(i32 %r13)
+	ret i32 0
+}
@@ -0,0 +1,58 @@
+declare void @printInt(i32)
+define i32 @main() {
+entry:
+	%r1 = add i32 1, 2
+	%r2 = shl i32 %r1, 3
+	%r3 = and i32 %r2, 255
+	%loc_a = alloca i32
+	store i32 %r3, i32* %loc_a
+	%a0 = load i32, i32* %loc_a
+	call void @printInt(i32 %a0)
+	%r4 = srem i32 7, 3
+	%r5 = mul i32 %r4, 2
+	%loc_b = alloca i32
+	store i32 %r5, i32* %loc_b
+	%b0 = load i32, i32* %loc_b
+	call void @printInt(i32 %b0)
+	%r6 = srem i32 -7, 3
+	call void @printInt(i32 %r6)
+	%r7 = srem i32 7, -3
+	call void @printInt(i32 %r7)
+	%r8 = and i32 5, 12
+	%r9 = xor i32 %b0, %r8
+	%r10 = or i32 %a0, %r9
+	%loc_c = alloca i32
+	store i32 %r10, i32* %loc_c
+	%c0 = load i32, i32* %loc_c
+	call void @printInt(i32 %c0)
+	%r11 = shl i32 1, 0
+	call void @printInt(i32 %r11)
+	%r12 = shl i32 1, 1
+	call void @printInt(i32 %r12)
+	%loc_d = alloca i32
+	store i32 40, i32* %loc_d
+	%r13 = ashr i32 -16, 2
+	call void @printInt(i32 %r13)
+	%d0 = load i32, i32* %loc_d
+	%r14 = and i32 %d0, 31
+	%r15 = ashr i32 -16, %r14
+	call void @printInt(i32 %r15)
+	%r16 = shl i32 1, 31
+	call void @printInt(i32 %r16)
+	%r17 = icmp eq i32 %d0, -1
+	%r18 = select i1 %r17, i32 1, i32 %d0
+	%r19 = srem i32 %a0, %r18
+	%r20 = add i32 %r19, %c0
+	%loc_e = alloca i32
+	store i32 %r20, i32* %loc_e
+	%e0 = load i32, i32* %loc_e
+	%r21 = xor i32 %e0, %e0
+	%r22 = ashr i32 %d0, 1
+	%r23 = or i32 %r21, %r22
+	call void @printInt(i32 %r23)
+	%r24 = or i32 %a0, 2
+	%r25 = sub i32 %b0, 1
+	%r26 = and i32 %r24, %r25
+	call void @printInt(i32 %r26)
+	ret i32 0
+}
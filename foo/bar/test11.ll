@@ -0,0 +1,150 @@
+declare void @printInt(i32)
+define i32 @main() {
+entry:
+	%loc_a = alloca i32
+	store i32 7, i32* %loc_a
+	%loc_b = alloca i32
+	store i32 0, i32* %loc_b
+	%a0 = load i32, i32* %loc_a
+	%r1 = icmp sgt i32 %a0, 5
+	%loc_c = alloca i32
+	%loc_d = alloca i32
+	%loc_e = alloca i32
+	%loc_x = alloca i32
+	br i1 %r1, label %L0, label %L2
+L0:
+	store i32 1, i32* %loc_c
+	%a1 = load i32, i32* %loc_a
+	call void @printInt(i32 %a1)
+	br label %L1
+L2:
+	store i32 2, i32* %loc_c
+	br label %L1
+L1:
+	%c0 = load i32, i32* %loc_c
+	call void @printInt(i32 %c0)
+	%b0 = load i32, i32* %loc_b
+	%r2 = icmp ne i32 %b0, 0
+	br i1 %r2, label %L6, label %L5
+L6:
+	%a2 = load i32, i32* %loc_a
+	%b1 = load i32, i32* %loc_b
+	%r3 = icmp eq i32 %b1, -1
+	%r4 = select i1 %r3, i32 1, i32 %b1
+	%r5 = sdiv i32 %a2, %r4
+	%r6 = sub i32 0, %r5
+	%r7 = select i1 %r3, i32 %r6, i32 %r5
+	%r8 = icmp sgt i32 %r7, 1
+	br i1 %r8, label %L3, label %L5
+L3:
+	call void @printInt(i32 100)
+	br label %L4
+L5:
+	call void @printInt(i32 200)
+	br label %L4
+L4:
+	%b2 = load i32, i32* %loc_b
+	%r9 = icmp eq i32 %b2, 0
+	br i1 %r9, label %L7, label %L9
+L9:
+	%a3 = load i32, i32* %loc_a
+	%b3 = load i32, i32* %loc_b
+	%r10 = icmp eq i32 %b3, -1
+	%r11 = select i1 %r10, i32 1, i32 %b3
+	%r12 = sdiv i32 %a3, %r11
+	%r13 = sub i32 0, %r12
+	%r14 = select i1 %r10, i32 %r13, i32 %r12
+	%r15 = icmp sgt i32 %r14, 1
+	br i1 %r15, label %L7, label %L8
+L7:
+	call void @printInt(i32 300)
+	br label %L8
+L8:
+	%a4 = load i32, i32* %loc_a
+	%b4 = load i32, i32* %loc_b
+	%r16 = icmp sle i32 %a4, %b4
+	br i1 %r16, label %L12, label %L13
+L13:
+	%a5 = load i32, i32* %loc_a
+	%r17 = icmp sge i32 %a5, 7
+	br i1 %r17, label %L10, label %L12
+L10:
+	%a6 = load i32, i32* %loc_a
+	%r18 = mul i32 %a6, 2
+	store i32 %r18, i32* %loc_d
+	br label %L11
+L12:
+	%a7 = load i32, i32* %loc_a
+	%r19 = icmp eq i32 %a7, 3
+	br i1 %r19, label %L14, label %L16
+L14:
+	store i32 3, i32* %loc_d
+	br label %L15
+L16:
+	store i32 -1, i32* %loc_d
+	br label %L15
+L15:
+	br label %L11
+L11:
+	%d0 = load i32, i32* %loc_d
+	call void @printInt(i32 %d0)
+	%a8 = load i32, i32* %loc_a
+	%b5 = load i32, i32* %loc_b
+	%r20 = icmp slt i32 %a8, %b5
+	br i1 %r20, label %L17, label %L20
+L20:
+	%b6 = load i32, i32* %loc_b
+	%r21 = icmp slt i32 %b6, 0
+	br i1 %r21, label %L21, label %L19
+L21:
+	%a9 = load i32, i32* %loc_a
+	%r22 = icmp ne i32 %a9, 7
+	br i1 %r22, label %L17, label %L19
+L17:
+	call void @printInt(i32 1)
+	br label %L18
+L19:
+	%c1 = load i32, i32* %loc_c
+	%r23 = icmp eq i32 %c1, 1
+	br i1 %r23, label %L22, label %L23
+L22:
+	%d1 = load i32, i32* %loc_d
+	%r24 = icmp sge i32 %d1, 14
+	br i1 %r24, label %L24, label %L25
+L24:
+	store i32 4, i32* %loc_e
+	br label %L25
+L25:
+	%b7 = load i32, i32* %loc_b
+	%r25 = add i32 %b7, 1
+	store i32 %r25, i32* %loc_b
+	br label %L23
+L23:
+	%b8 = load i32, i32* %loc_b
+	call void @printInt(i32 %b8)
+	br label %L18
+L18:
+	%a10 = load i32, i32* %loc_a
+	%r26 = sub i32 %a10, 8
+	%r27 = icmp slt i32 %r26, 0
+	br i1 %r27, label %L26, label %L28
+L26:
+	br label %L27
+L28:
+	call void @printInt(i32 5)
+	br label %L27
+L27:
+	store i32 1, i32* %loc_x
+	%b9 = load i32, i32* %loc_b
+	%r28 = icmp sgt i32 %b9, 0
+	br i1 %r28, label %L29, label %L30
+L29:
+	store i32 2, i32* %loc_x
+	br label %L30
+L30:
+	%x0 = load i32, i32* %loc_x
+	%c2 = load i32, i32* %loc_c
+	%r29 = add i32 %x0, %c2
+	call void @printInt(i32 %r29)
+	ret i32 0
+}
@@ -0,0 +1,154 @@
+declare void @printInt(i32)
+define i32 @main() {
+entry:
+	%loc_i = alloca i32
+	store i32 0, i32* %loc_i
+	%loc_sum = alloca i32
+	store i32 0, i32* %loc_sum
+	%loc_a = alloca i32
+	%loc_b = alloca i32
+	%loc_n = alloca i32
+	%loc_t = alloca i32
+	%loc_x = alloca i32
+	%loc_total = alloca i32
+	%loc_j = alloca i32
+	%loc_d = alloca i32
+	%loc_k = alloca i32
+	br label %L0
+L0:
+	%i0 = load i32, i32* %loc_i
+	%r1 = icmp slt i32 %i0, 10
+	br i1 %r1, label %L1, label %L2
+L1:
+	%sum0 = load i32, i32* %loc_sum
+	%i1 = load i32, i32* %loc_i
+	%r2 = add i32 %sum0, %i1
+	store i32 %r2, i32* %loc_sum
+	%r3 = add i32 %i1, 1
+	store i32 %r3, i32* %loc_i
+	br label %L0
+L2:
+	%sum1 = load i32, i32* %loc_sum
+	call void @printInt(i32 %sum1)
+	store i32 0, i32* %loc_a
+	store i32 1, i32* %loc_b
+	store i32 0, i32* %loc_n
+	br label %L3
+L3:
+	%n0 = load i32, i32* %loc_n
+	%r4 = icmp slt i32 %n0, 20
+	br i1 %r4, label %L4, label %L5
+L4:
+	%a0 = load i32, i32* %loc_a
+	%b0 = load i32, i32* %loc_b
+	%r5 = add i32 %a0, %b0
+	store i32 %r5, i32* %loc_t
+	store i32 %b0, i32* %loc_a
+	%t0 = load i32, i32* %loc_t
+	store i32 %t0, i32* %loc_b
+	%n1 = load i32, i32* %loc_n
+	%r6 = add i32 %n1, 1
+	store i32 %r6, i32* %loc_n
+	br label %L3
+L5:
+	%a1 = load i32, i32* %loc_a
+	call void @printInt(i32 %a1)
+	br label %L6
+L6:
+	%a2 = load i32, i32* %loc_a
+	%r7 = icmp slt i32 %a2, 0
+	br i1 %r7, label %L7, label %L8
+L7:
+	%a3 = load i32, i32* %loc_a
+	call void @printInt(i32 %a3)
+	br label %L6
+L8:
+	store i32 1, i32* %loc_x
+	br label %L9
+L9:
+	%x0 = load i32, i32* %loc_x
+	%r8 = icmp slt i32 %x0, 1000
+	br i1 %r8, label %L10, label %L11
+L10:
+	%x1 = load i32, i32* %loc_x
+	%r9 = srem i32 %x1, 3
+	%r10 = icmp eq i32 %r9, 0
+	br i1 %r10, label %L12, label %L14
+L12:
+	%x2 = load i32, i32* %loc_x
+	%r11 = mul i32 %x2, 2
+	store i32 %r11, i32* %loc_x
+	br label %L13
+L14:
+	%x3 = load i32, i32* %loc_x
+	%r12 = mul i32 %x3, 3
+	%r13 = add i32 %r12, 1
+	store i32 %r13, i32* %loc_x
+	br label %L13
+L13:
+	br label %L9
+L11:
+	%x4 = load i32, i32* %loc_x
+	call void @printInt(i32 %x4)
+	store i32 0, i32* %loc_i
+	store i32 0, i32* %loc_total
+	br label %L15
+L15:
+	%i2 = load i32, i32* %loc_i
+	%r14 = icmp slt i32 %i2, 4
+	br i1 %r14, label %L16, label %L17
+L16:
+	store i32 0, i32* %loc_j
+	br label %L18
+L18:
+	%j0 = load i32, i32* %loc_j
+	%i3 = load i32, i32* %loc_i
+	%r15 = icmp sle i32 %j0, %i3
+	br i1 %r15, label %L19, label %L20
+L19:
+	%i4 = load i32, i32* %loc_i
+	%j1 = load i32, i32* %loc_j
+	%r16 = mul i32 %i4, %j1
+	%total0 = load i32, i32* %loc_total
+	%r17 = add i32 %total0, %r16
+	store i32 %r17, i32* %loc_total
+	%r18 = add i32 %j1, 1
+	store i32 %r18, i32* %loc_j
+	br label %L18
+L20:
+	%i5 = load i32, i32* %loc_i
+	%r19 = add i32 %i5, 1
+	store i32 %r19, i32* %loc_i
+	br label %L15
+L17:
+	%total1 = load i32, i32* %loc_total
+	call void @printInt(i32 %total1)
+	store i32 0, i32* %loc_d
+	store i32 5, i32* %loc_k
+	br label %L21
+L21:
+	%d0 = load i32, i32* %loc_d
+	%r20 = icmp eq i32 %d0, 0
+	br i1 %r20, label %L22, label %L24
+L24:
+	%k0 = load i32, i32* %loc_k
+	%d1 = load i32, i32* %loc_d
+	%r21 = icmp eq i32 %d1, -1
+	%r22 = select i1 %r21, i32 1, i32 %d1
+	%r23 = sdiv i32 %k0, %r22
+	%r24 = sub i32 0, %r23
+	%r25 = select i1 %r21, i32 %r24, i32 %r23
+	%r26 = icmp sgt i32 %r25, 1
+	br i1 %r26, label %L22, label %L23
+L22:
+	%d2 = load i32, i32* %loc_d
+	%r27 = add i32 %d2, 1
+	store i32 %r27, i32* %loc_d
+	br label %L21
+L23:
+	%d3 = load i32, i32* %loc_d
+	call void @printInt(i32 %d3)
+	%k1 = load i32, i32* %loc_k
+	call void @printInt(i32 %k1)
+	ret i32 0
+}
@@ -0,0 +1,176 @@
+declare void @printInt(i32)
+define i32 @fib(i32 %n) {
+entry:
+	%loc_n = alloca i32
+	store i32 %n, i32* %loc_n
+	%n0 = load i32, i32* %loc_n
+	%r1 = icmp slt i32 %n0, 2
+	br i1 %r1, label %L0, label %L1
+L0:
+	%n1 = load i32, i32* %loc_n
+	ret i32 %n1
+L1:
+	%n2 = load i32, i32* %loc_n
+	%r2 = sub i32 %n2, 1
+	%r3 = call i32 @fib(i32 %r2)
+	%r4 = sub i32 %n2, 2
+	%r5 = call i32 @fib(i32 %r4)
+	%r6 = add i32 %r3, %r5
+	ret i32 %r6
+}
+define i32 @gcd(i32 %a, i32 %b) {
+entry:
+	%loc_a = alloca i32
+	store i32 %a, i32* %loc_a
+	%loc_b = alloca i32
+	store i32 %b, i32* %loc_b
+	%loc_t = alloca i32
+	br label %L0
+L0:
+	%b0 = load i32, i32* %loc_b
+	%r1 = icmp ne i32 %b0, 0
+	br i1 %r1, label %L1, label %L2
+L1:
+	%a0 = load i32, i32* %loc_a
+	%b1 = load i32, i32* %loc_b
+	%r2 = icmp eq i32 %b1, -1
+	%r3 = select i1 %r2, i32 1, i32 %b1
+	%r4 = srem i32 %a0, %r3
+	store i32 %r4, i32* %loc_t
+	store i32 %b1, i32* %loc_a
+	%t0 = load i32, i32* %loc_t
+	store i32 %t0, i32* %loc_b
+	br label %L0
+L2:
+	%a1 = load i32, i32* %loc_a
+	ret i32 %a1
+}
+define i32 @show(i32 %x) {
+entry:
+	%loc_x = alloca i32
+	store i32 %x, i32* %loc_x
+	%x0 = load i32, i32* %loc_x
+	call void @printInt(i32 %x0)
+	%r1 = mul i32 %x0, 2
+	ret i32 %r1
+}
+define i32 @sign(i32 %x) {
+entry:
+	%loc_x = alloca i32
+	store i32 %x, i32* %loc_x
+	%x0 = load i32, i32* %loc_x
+	%r1 = icmp slt i32 %x0, 0
+	br i1 %r1, label %L0, label %L2
+L0:
+	ret i32 -1
+L2:
+	%x1 = load i32, i32* %loc_x
+	%r2 = icmp eq i32 %x1, 0
+	br i1 %r2, label %L3, label %L4
+L3:
+	ret i32 0
+L4:
+	br label %L1
+L1:
+	ret i32 1
+}
+define i32 @pick(i32 %a, i32 %b, i32 %c, i32 %d, i32 %e) {
+entry:
+	%loc_a = alloca i32
+	store i32 %a, i32* %loc_a
+	%loc_b = alloca i32
+	store i32 %b, i32* %loc_b
+	%loc_c = alloca i32
+	store i32 %c, i32* %loc_c
+	%loc_d = alloca i32
+	store i32 %d, i32* %loc_d
+	%loc_e = alloca i32
+	store i32 %e, i32* %loc_e
+	%a0 = load i32, i32* %loc_a
+	%b0 = load i32, i32* %loc_b
+	%r1 = icmp sgt i32 %a0, %b0
+	%loc_r = alloca i32
+	br i1 %r1, label %L0, label %L2
+L0:
+	%c0 = load i32, i32* %loc_c
+	store i32 %c0, i32* %loc_r
+	br label %L1
+L2:
+	%d0 = load i32, i32* %loc_d
+	%e0 = load i32, i32* %loc_e
+	%r2 = sub i32 %d0, %e0
+	ret i32 %r2
+L1:
+	%e1 = load i32, i32* %loc_e
+	%r3 = call i32 @show(i32 %e1)
+	%r0 = load i32, i32* %loc_r
+	%r4 = add i32 %r0, %r3
+	ret i32 %r4
+}
+define i32 @main() {
+entry:
+	%r1 = call i32 @fib(i32 20)
+	call void @printInt(i32 %r1)
+	%r2 = call i32 @gcd(i32 1071, i32 462)
+	call void @printInt(i32 %r2)
+	%r3 = call i32 @show(i32 1)
+	%r4 = call i32 @show(i32 2)
+	%r5 = call i32 @show(i32 3)
+	%r6 = mul i32 %r4, %r5
+	%r7 = add i32 %r3, %r6
+	call void @printInt(i32 %r7)
+	%loc_x = alloca i32
+	store i32 7, i32* %loc_x
+	%x0 = load i32, i32* %loc_x
+	%r8 = call i32 @show(i32 %x0)
+	%r9 = add i32 %x0, 1
+	%r10 = call i32 @show(i32 %r9)
+	%r11 = sub i32 %r8, %r10
+	%loc_y = alloca i32
+	store i32 %r11, i32* %loc_y
+	%y0 = load i32, i32* %loc_y
+	call void @printInt(i32 %y0)
+	%r12 = call i32 @sign(i32 -5)
+	%r13 = call i32 @sign(i32 0)
+	%r14 = mul i32 %r13, 10
+	%r15 = add i32 %r12, %r14
+	%r16 = call i32 @sign(i32 %x0)
+	%r17 = mul i32 %r16, 100
+	%r18 = add i32 %r15, %r17
+	call void @printInt(i32 %r18)
+	%r19 = call i32 @pick(i32 2, i32 1, i32 3, i32 4, i32 5)
+	call void @printInt(i32 %r19)
+	%r20 = call i32 @pick(i32 1, i32 2, i32 3, i32 4, i32 5)
+	call void @printInt(i32 %r20)
+	%r21 = call i32 @fib(i32 10)
+	%r22 = icmp eq i32 %r21, 55
+	%loc_i = alloca i32
+	br i1 %r22, label %L2, label %L1
+L2:
+	%r23 = call i32 @gcd(i32 12, i32 18)
+	%r24 = call i32 @show(i32 4)
+	%r25 = icmp slt i32 %r23, %r24
+	br i1 %r25, label %L0, label %L1
+L0:
+	%r26 = call i32 @gcd(i32 21, i32 14)
+	%r27 = call i32 @fib(i32 %r26)
+	call void @printInt(i32 %r27)
+	br label %L1
+L1:
+	store i32 0, i32* %loc_i
+	br label %L3
+L3:
+	%i0 = load i32, i32* %loc_i
+	%r28 = call i32 @show(i32 %i0)
+	%r29 = icmp slt i32 %r28, 6
+	br i1 %r29, label %L4, label %L5
+L4:
+	%i1 = load i32, i32* %loc_i
+	%r30 = add i32 %i1, 1
+	store i32 %r30, i32* %loc_i
+	br label %L3
+L5:
+	%i2 = load i32, i32* %loc_i
+	call void @printInt(i32 %i2)
+	ret i32 0
+}
@@ -1,34 +1,333 @@
 use std::fmt;
 
-#[derive(Debug)]
+/// Byte offsets `[start, end)` into source code, as reported by lalrpop.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    pub fn new(start: usize, end: usize) -> Span {
+        Span { start, end }
+    }
+}
+
+#[derive(Debug, Clone)]
 pub enum Stmt {
     SAss(String, Box<Expr>),
     SExpr(Box<Expr>),
+    SIf(Box<Cond>, Vec<Box<Stmt>>, Vec<Box<Stmt>>), // else branch is empty when omitted
+    SWhile(Box<Cond>, Vec<Box<Stmt>>),
+    SFn(Box<Function>), // only at the top level of program, never executed in place
+    SReturn(Box<Expr>, Span), // span of `return` keyword
 }
 
-#[derive(Debug)]
+/// `fn name(params) { body }`; every path through body ends with `return`. Functions only see their
+/// parameters and their own variables and can be called from anywhere in the program, recursively too.
+#[derive(Debug, Clone)]
+pub struct Function {
+    pub name: String,
+    pub params: Vec<String>,
+    pub param_spans: Vec<Span>, // of each parameter
+    pub body: Vec<Box<Stmt>>,
+    pub span: Span, // of the name
+}
+
+/// Condition of `if` and `while`. Conditions aren't values: they only decide which branch is taken,
+/// so `&&` and `||` can be compiled to jumps and skip evaluating their right operand.
+#[derive(Debug, Clone)]
+pub enum Cond {
+    Cmp(Box<Expr>, Relation, Box<Expr>),
+    And(Box<Cond>, Box<Cond>),
+    Or(Box<Cond>, Box<Cond>),
+    Not(Box<Cond>),
+}
+
+#[derive(Debug, Clone)]
 pub enum Expr {
     Number(i32),
-    Ident(String),
+    Ident(String, Span),
     Op(Box<Expr>, Opcode, Box<Expr>),
+    Neg(Box<Expr>),
+    Call(String, Vec<Box<Expr>>, Span), // arguments are evaluated left to right
+}
+
+/// Function declarations found at the top level of program, in source order
+pub fn functions(stmts: &[Box<Stmt>]) -> impl Iterator<Item = &Function> {
+    stmts.iter().filter_map(|stmt| match stmt.as_ref() {
+        Stmt::SFn(function) => Some(function.as_ref()),
+        _ => None,
+    })
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
 pub enum Opcode {
     Add,
     Sub,
     Mul,
     Div,
+    Rem,
+    And,
+    Or,
+    Xor,
+    Shl,
+    Shr, // arithmetic
+}
+
+/// Signed comparison of two integers
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Relation {
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Eq,
+    Ne,
 }
 
 impl fmt::Display for Opcode {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        use Opcode::{Add, Div, Mul, Sub};
+        use Opcode::*;
         match self {
             Add => write!(f, "add"),
             Sub => write!(f, "sub"),
             Mul => write!(f, "mul"),
             Div => write!(f, "sdiv"),
+            Rem => write!(f, "srem"),
+            And => write!(f, "and"),
+            Or => write!(f, "or"),
+            Xor => write!(f, "xor"),
+            Shl => write!(f, "shl"),
+            Shr => write!(f, "ashr"),
+        }
+    }
+}
+
+impl Opcode {
+    /// Operator as written in Instant source code
+    pub fn symbol(&self) -> &'static str {
+        use Opcode::*;
+        match self {
+            Add => "+",
+            Sub => "-",
+            Mul => "*",
+            Div => "/",
+            Rem => "%",
+            And => "&",
+            Or => "|",
+            Xor => "^",
+            Shl => "<<",
+            Shr => ">>",
+        }
+    }
+
+    /// Binding strength in grammar, higher binds tighter
+    pub fn precedence(&self) -> u8 {
+        use Opcode::*;
+        match self {
+            Or => 1,
+            Xor => 2,
+            And => 3,
+            Shl | Shr => 4,
+            Add | Sub => 5,
+            Mul | Div | Rem => 6,
+        }
+    }
+
+    /// Whether operands can be evaluated in either order and swapped
+    pub fn is_commutative(&self) -> bool {
+        use Opcode::*;
+        matches!(self, Add | Mul | And | Or | Xor)
+    }
+
+    /// Division and remainder, which fail on zero divisor
+    pub fn is_division(&self) -> bool {
+        matches!(self, Opcode::Div | Opcode::Rem)
+    }
+}
+
+/// `icmp` predicate
+impl fmt::Display for Relation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use Relation::*;
+        match self {
+            Lt => write!(f, "slt"),
+            Le => write!(f, "sle"),
+            Gt => write!(f, "sgt"),
+            Ge => write!(f, "sge"),
+            Eq => write!(f, "eq"),
+            Ne => write!(f, "ne"),
+        }
+    }
+}
+
+impl Relation {
+    /// Operator as written in Instant source code
+    pub fn symbol(&self) -> &'static str {
+        use Relation::*;
+        match self {
+            Lt => "<",
+            Le => "<=",
+            Gt => ">",
+            Ge => ">=",
+            Eq => "==",
+            Ne => "!=",
+        }
+    }
+
+    /// Relation holding exactly when this one doesn't
+    pub fn negate(&self) -> Relation {
+        use Relation::*;
+        match self {
+            Lt => Ge,
+            Le => Gt,
+            Gt => Le,
+            Ge => Lt,
+            Eq => Ne,
+            Ne => Eq,
+        }
+    }
+
+    /// Same relation with operands swapped, `a < b` is `b > a`
+    pub fn flip(&self) -> Relation {
+        use Relation::*;
+        match self {
+            Lt => Gt,
+            Le => Ge,
+            Gt => Lt,
+            Ge => Le,
+            Eq => Eq,
+            Ne => Ne,
+        }
+    }
+
+    pub fn holds(&self, l: i32, r: i32) -> bool {
+        use Relation::*;
+        match self {
+            Lt => l < r,
+            Le => l <= r,
+            Gt => l > r,
+            Ge => l >= r,
+            Eq => l == r,
+            Ne => l != r,
+        }
+    }
+}
+
+/// Prints statement back as Instant source code
+impl fmt::Display for Stmt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Stmt::SAss(ident, expr) => write!(f, "{} = {}", ident, expr),
+            Stmt::SExpr(expr) => write!(f, "{}", expr),
+            Stmt::SIf(cond, then, otherwise) => {
+                write!(f, "if ({}) {}", cond, Block(then))?;
+
+                if otherwise.is_empty() {
+                    Ok(())
+                } else {
+                    write!(f, " else {}", Block(otherwise))
+                }
+            }
+            Stmt::SWhile(cond, body) => write!(f, "while ({}) {}", cond, Block(body)),
+            Stmt::SFn(function) => write!(
+                f,
+                "fn {}({}) {}",
+                function.name,
+                function.params.join(", "),
+                Block(&function.body)
+            ),
+            Stmt::SReturn(expr, _) => write!(f, "return {}", expr),
+        }
+    }
+}
+
+struct Block<'a>(&'a [Box<Stmt>]);
+
+impl fmt::Display for Block<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.0.is_empty() {
+            return write!(f, "{{}}");
+        }
+
+        let stmts: Vec<String> = self.0.iter().map(|stmt| stmt.to_string()).collect();
+        write!(f, "{{ {} }}", stmts.join("; "))
+    }
+}
+
+/// `||` binds looser than `&&`, both are left associative; `!` applies to a parenthesised condition
+/// unless it's another negation.
+impl fmt::Display for Cond {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Cond::Cmp(l_expr, relation, r_expr) => {
+                write!(f, "{} {} {}", l_expr, relation.symbol(), r_expr)
+            }
+            Cond::Or(l_cond, r_cond) => match r_cond.as_ref() {
+                Cond::Or(_, _) => write!(f, "{} || ({})", l_cond, r_cond),
+                _ => write!(f, "{} || {}", l_cond, r_cond),
+            },
+            Cond::And(l_cond, r_cond) => {
+                match l_cond.as_ref() {
+                    Cond::Or(_, _) => write!(f, "({}) && ", l_cond)?,
+                    _ => write!(f, "{} && ", l_cond)?,
+                }
+
+                match r_cond.as_ref() {
+                    Cond::Or(_, _) | Cond::And(_, _) => write!(f, "({})", r_cond),
+                    _ => write!(f, "{}", r_cond),
+                }
+            }
+            Cond::Not(cond) => match cond.as_ref() {
+                Cond::Not(_) => write!(f, "!{}", cond),
+                _ => write!(f, "!({})", cond),
+            },
+        }
+    }
+}
+
+/// Prints expression as Instant source code with only the parentheses needed to parse it back
+/// into the same tree (all binary operators are left associative, unary minus binds tightest).
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Expr::Number(n) => write!(f, "{}", n),
+            Expr::Ident(ident, _) => write!(f, "{}", ident),
+            // `-5` would parse back as negative literal rather than negation
+            Expr::Neg(expr) => match expr.as_ref() {
+                Expr::Number(n) if *n >= 0 => write!(f, "-({})", n),
+                Expr::Op(_, _, _) => write!(f, "-({})", expr),
+                _ => write!(f, "-{}", expr),
+            },
+            Expr::Op(l_expr, opcode, r_expr) => {
+                let precedence = opcode.precedence();
+
+                match l_expr.precedence() {
+                    Some(p) if p < precedence => write!(f, "({})", l_expr)?,
+                    _ => write!(f, "{}", l_expr)?,
+                }
+
+                write!(f, " {} ", opcode.symbol())?;
+
+                match r_expr.precedence() {
+                    Some(p) if p <= precedence => write!(f, "({})", r_expr),
+                    _ => write!(f, "{}", r_expr),
+                }
+            }
+            Expr::Call(name, args, _) => {
+                let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+                write!(f, "{}({})", name, args.join(", "))
+            }
+        }
+    }
+}
+
+impl Expr {
+    fn precedence(&self) -> Option<u8> {
+        match self {
+            Expr::Op(_, opcode, _) => Some(opcode.precedence()),
+            _ => None,
         }
     }
 }
@@ -0,0 +1,565 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::convert::TryFrom;
+use std::iter;
+
+use crate::ast::Relation;
+use crate::ir::Label;
+use crate::jvm::{self, JVMInstruction, Method};
+
+// Version 52 (Java 8): verifier checks types against StackMapTable frames instead of inferring them,
+// so every method has a frame at every label (see stack_map_table).
+const MAJOR_VERSION: u16 = 52;
+const MINOR_VERSION: u16 = 0;
+
+const ACC_PUBLIC: u16 = 0x0001;
+const ACC_STATIC: u16 = 0x0008;
+const ACC_SUPER: u16 = 0x0020;
+
+/// Serializes class with default constructor, `main` and function methods straight into binary
+/// class file format, without going through Jasmin.
+pub fn write_class(class_name: &str, main: &Method, functions: &[Method]) -> Vec<u8> {
+    let mut pool = ConstantPool::new();
+
+    let this_class = pool.class(class_name);
+    let super_class = pool.class("java/lang/Object");
+    let code_name = pool.utf8("Code");
+
+    let init_name = pool.utf8("<init>");
+    let init_descriptor = pool.utf8("()V");
+    let object_init = pool.method_ref("java/lang/Object", "<init>", "()V");
+    let init_code = Code {
+        max_stack: 1,
+        max_locals: 1,
+        attributes: vec![],
+        bytes: vec![
+            0x2a, // aload_0
+            0xb7, // invokespecial
+            (object_init >> 8) as u8,
+            object_init as u8,
+            0xb1, // return
+        ],
+    };
+
+    let mut out = vec![];
+    put_u4(&mut out, 0xCAFE_BABE);
+    put_u2(&mut out, MINOR_VERSION);
+    put_u2(&mut out, MAJOR_VERSION);
+
+    // Methods refer to the constant pool, which has to be complete before it's written
+    let mut methods = vec![];
+    write_method(&mut methods, ACC_PUBLIC, init_name, init_descriptor, code_name, &init_code);
+    for method in iter::once(main).chain(functions.iter()) {
+        let descriptor = match method.arity {
+            Some(arity) => jvm::descriptor(arity),
+            None => "([Ljava/lang/String;)V".to_string(),
+        };
+        let (name, descriptor) = (pool.utf8(method.name), pool.utf8(&descriptor));
+        let code = method_code(class_name, method, &mut pool);
+
+        write_method(&mut methods, ACC_PUBLIC | ACC_STATIC, name, descriptor, code_name, &code);
+    }
+
+    pool.write(&mut out);
+    put_u2(&mut out, ACC_PUBLIC | ACC_SUPER);
+    put_u2(&mut out, this_class);
+    put_u2(&mut out, super_class);
+    put_u2(&mut out, 0); // interfaces
+    put_u2(&mut out, 0); // fields
+    put_u2(&mut out, 2 + functions.len() as u16); // methods
+    out.extend(methods);
+    put_u2(&mut out, 0); // class attributes
+
+    out
+}
+
+/// `main` ends with `return`, functions end with their own `ireturn`.
+/// Jumps whose offset doesn't fit in 16 bits are widened (see `widen`) and the code is encoded again,
+/// until every jump fits.
+fn method_code(class_name: &str, method: &Method, pool: &mut ConstantPool) -> Code {
+    let mut instructions = method.instructions.clone();
+    let mut wide = HashSet::new(); // indices of `goto` encoded as `goto_w`
+
+    let (bytes, labels) = loop {
+        let mut bytes = vec![];
+        let mut jumps = Jumps::new();
+        let mut starts = HashMap::new();
+        for (i, instruction) in instructions.iter().enumerate() {
+            starts.insert(bytes.len(), i);
+            encode_instruction(instruction, wide.contains(&i), class_name, pool, &mut jumps, &mut bytes);
+        }
+        if method.arity.is_none() {
+            bytes.push(0xb1); // return
+        }
+
+        let far = jumps.resolve(&mut bytes);
+        if far.is_empty() {
+            break (bytes, jumps.labels);
+        }
+
+        let far = far.iter().map(|position| starts[position]).collect();
+        let (widened, widened_gotos) = widen(&instructions, &far, &wide);
+        instructions = widened;
+        wide = widened_gotos;
+    };
+
+    let mut attributes = vec![];
+    if !labels.is_empty() {
+        let table = stack_map_table(method, &instructions, &labels, pool);
+        attributes.push((pool.utf8("StackMapTable"), table));
+    }
+
+    Code {
+        max_stack: method.limit_stack,
+        max_locals: method.limit_locals,
+        bytes,
+        attributes,
+    }
+}
+
+struct Code {
+    max_stack: usize,
+    max_locals: usize,
+    bytes: Vec<u8>,
+    attributes: Vec<(u16, Vec<u8>)>, // name and contents
+}
+
+fn write_method(out: &mut Vec<u8>, flags: u16, name: u16, descriptor: u16, code_name: u16, code: &Code) {
+    put_u2(out, flags);
+    put_u2(out, name);
+    put_u2(out, descriptor);
+    put_u2(out, 1); // attributes: Code only
+
+    let attributes_length: usize = code.attributes.iter().map(|(_, contents)| 2 + 4 + contents.len()).sum();
+
+    put_u2(out, code_name);
+    // max_stack, max_locals, code_length, code, exception_table_length, attributes_count, attributes
+    put_u4(out, (2 + 2 + 4 + code.bytes.len() + 2 + 2 + attributes_length) as u32);
+    put_u2(out, code.max_stack as u16);
+    put_u2(out, code.max_locals as u16);
+    put_u4(out, code.bytes.len() as u32);
+    out.extend_from_slice(&code.bytes);
+    put_u2(out, 0); // exception table
+    put_u2(out, code.attributes.len() as u16);
+    for (name, contents) in code.attributes.iter() {
+        put_u2(out, *name);
+        put_u4(out, contents.len() as u32);
+        out.extend_from_slice(contents);
+    }
+}
+
+/// Replaces far jumps (given by index) with ones reaching anywhere in the method: `goto` becomes
+/// `goto_w`, conditional jump is inverted to skip over `goto_w` to its target. The instruction after
+/// it gets a new label, so that it has a frame like any other jump target.
+/// Returns new instructions and indices of the gotos to encode as `goto_w`, including `wide` ones.
+fn widen<'a>(
+    instructions: &[JVMInstruction<'a>],
+    far: &HashSet<usize>,
+    wide: &HashSet<usize>,
+) -> (Vec<JVMInstruction<'a>>, HashSet<usize>) {
+    let mut next_label = instructions
+        .iter()
+        .filter_map(|instruction| match *instruction {
+            JVMInstruction::Label(Label(n)) => Some(n + 1),
+            _ => None,
+        })
+        .max()
+        .unwrap_or(0);
+
+    let mut widened = vec![];
+    let mut widened_gotos = HashSet::new();
+    for (i, instruction) in instructions.iter().enumerate() {
+        match *instruction {
+            JVMInstruction::IfICmp(relation, label) if far.contains(&i) => {
+                let skip = Label(next_label);
+                next_label += 1;
+
+                widened.push(JVMInstruction::IfICmp(relation.negate(), skip));
+                widened_gotos.insert(widened.len());
+                widened.push(JVMInstruction::Goto(label));
+                widened.push(JVMInstruction::Label(skip));
+            }
+            _ => {
+                if far.contains(&i) || wide.contains(&i) {
+                    widened_gotos.insert(widened.len());
+                }
+                widened.push(*instruction);
+            }
+        }
+    }
+
+    (widened, widened_gotos)
+}
+
+/// One `full_frame` per label offset. The stack is empty at every label (see `jvm::compile_method`).
+/// Slot 0 of `main` holds `String[]` arguments, function arguments are `int`; other slots are `int`
+/// once stored on every path reaching the label, otherwise `top`.
+fn stack_map_table(
+    method: &Method,
+    instructions: &[JVMInstruction],
+    offsets: &HashMap<Label, usize>,
+    pool: &mut ConstantPool,
+) -> Vec<u8> {
+    let mut entry = vec![false; method.limit_locals];
+    let args = match method.arity {
+        Some(arity) => {
+            entry[..arity].iter_mut().for_each(|slot| *slot = true);
+            None
+        }
+        None => {
+            entry[0] = true;
+            Some(pool.class("[Ljava/lang/String;"))
+        }
+    };
+    let assigned = assigned_at_labels(instructions, entry);
+
+    // Labels sharing an offset are reached through the last one, whose slots are the intersection
+    let mut frames = BTreeMap::new();
+    for instruction in instructions.iter() {
+        if let JVMInstruction::Label(label) = instruction {
+            if let Some(slots) = assigned.get(label) {
+                frames.insert(offsets[label], slots);
+            }
+        }
+    }
+
+    let mut out = vec![];
+    put_u2(&mut out, frames.len() as u16);
+
+    let mut previous: Option<usize> = None;
+    for (offset, slots) in frames.into_iter() {
+        let locals = slots.iter().rposition(|assigned| *assigned).map_or(0, |slot| slot + 1);
+
+        out.push(255); // full_frame
+        put_u2(&mut out, previous.map_or(offset, |previous| offset - previous - 1) as u16);
+        put_u2(&mut out, locals as u16);
+        for (slot, assigned) in slots[..locals].iter().enumerate() {
+            match args {
+                Some(args) if slot == 0 => {
+                    out.push(7); // Object
+                    put_u2(&mut out, args);
+                }
+                _ => out.push(if *assigned { 1 } else { 0 }), // Integer or Top
+            }
+        }
+        put_u2(&mut out, 0); // stack items
+
+        previous = Some(offset);
+    }
+
+    out
+}
+
+/// Slots stored on every path reaching each label, starting with `entry` slots holding arguments.
+/// Only ints are ever stored, so knowing which slots were stored is enough to type them. Jumps back
+/// to loop heads can only remove slots, so passes over the code are repeated until nothing changes.
+fn assigned_at_labels(instructions: &[JVMInstruction], entry: Vec<bool>) -> HashMap<Label, Vec<bool>> {
+    let mut at_labels: HashMap<Label, Vec<bool>> = HashMap::new();
+
+    let meet = |at_labels: &mut HashMap<Label, Vec<bool>>, label: Label, assigned: &[bool]| {
+        let slots = match at_labels.get_mut(&label) {
+            Some(slots) => slots,
+            None => {
+                at_labels.insert(label, assigned.to_vec());
+                return true;
+            }
+        };
+        let before = slots.clone();
+
+        for (slot, assigned) in slots.iter_mut().zip(assigned.iter()) {
+            *slot = *slot && *assigned;
+        }
+
+        *slots != before
+    };
+
+    loop {
+        let mut changed = false;
+        let mut assigned = Some(entry.clone()); // None after goto and ireturn, until the next label
+
+        for instruction in instructions.iter() {
+            match *instruction {
+                JVMInstruction::IStore(i) => {
+                    if let Some(assigned) = assigned.as_mut() {
+                        assigned[i] = true;
+                    }
+                }
+                JVMInstruction::Label(label) => {
+                    if let Some(assigned) = assigned.as_ref() {
+                        changed |= meet(&mut at_labels, label, assigned);
+                    }
+                    assigned = at_labels.get(&label).cloned();
+                }
+                JVMInstruction::Goto(label) | JVMInstruction::IfICmp(_, label) => {
+                    if let Some(assigned) = assigned.as_ref() {
+                        changed |= meet(&mut at_labels, label, assigned);
+                    }
+                    if let JVMInstruction::Goto(_) = instruction {
+                        assigned = None;
+                    }
+                }
+                JVMInstruction::IReturn => assigned = None,
+                _ => {}
+            }
+        }
+
+        if !changed {
+            return at_labels;
+        }
+    }
+}
+
+/// Offsets of labels and of jumps to them; jump offset is relative to the jump instruction
+/// and is only known once its label is encoded.
+struct Jumps {
+    labels: HashMap<Label, usize>,
+    jumps: Vec<(usize, Label, bool)>, // position, target and whether offset takes 4 bytes
+}
+
+impl Jumps {
+    fn new() -> Jumps {
+        Jumps {
+            labels: HashMap::new(),
+            jumps: vec![],
+        }
+    }
+
+    /// Jump opcode followed by placeholder offset
+    fn encode(&mut self, opcode: u8, label: Label, wide: bool, out: &mut Vec<u8>) {
+        self.jumps.push((out.len(), label, wide));
+        out.push(opcode);
+        if wide {
+            put_u4(out, 0);
+        } else {
+            put_u2(out, 0);
+        }
+    }
+
+    /// Fills in offsets; returns positions of jumps whose offset doesn't fit in 16 bits
+    fn resolve(&self, out: &mut [u8]) -> Vec<usize> {
+        let mut far = vec![];
+
+        for (position, label, wide) in self.jumps.iter() {
+            let offset = self.labels[label] as isize - *position as isize;
+
+            if *wide {
+                let offset = offset as i32;
+                out[position + 1..position + 5].copy_from_slice(&offset.to_be_bytes());
+            } else {
+                match i16::try_from(offset) {
+                    Ok(offset) => out[position + 1..position + 3].copy_from_slice(&offset.to_be_bytes()),
+                    Err(_) => far.push(*position),
+                }
+            }
+        }
+
+        far
+    }
+}
+
+/// `wide` jumps take 4-byte offset, only `goto` has such form
+fn encode_instruction(
+    instruction: &JVMInstruction,
+    wide: bool,
+    class_name: &str,
+    pool: &mut ConstantPool,
+    jumps: &mut Jumps,
+    out: &mut Vec<u8>,
+) {
+    use JVMInstruction::*;
+
+    match *instruction {
+        IConst(n) => out.push((0x03 + n) as u8), // iconst_m1 is 0x02
+        BiPush(n) => out.extend_from_slice(&[0x10, n as u8]),
+        SiPush(n) => {
+            out.push(0x11);
+            put_u2(out, n as u16);
+        }
+        Ldc(n) => {
+            let index = pool.integer(n);
+            if index <= 0xff {
+                out.extend_from_slice(&[0x12, index as u8]);
+            } else {
+                out.push(0x13); // ldc_w
+                put_u2(out, index);
+            }
+        }
+        ILoad(i @ 0..=3) => out.push(0x1a + i as u8),
+        IStore(i @ 0..=3) => out.push(0x3b + i as u8),
+        ILoad(i) => encode_local(0x15, i as u16, out),
+        IStore(i) => encode_local(0x36, i as u16, out),
+        IAdd => out.push(0x60),
+        ISub => out.push(0x64),
+        IMul => out.push(0x68),
+        IDiv => out.push(0x6c),
+        IRem => out.push(0x70),
+        IAnd => out.push(0x7e),
+        IOr => out.push(0x80),
+        IXor => out.push(0x82),
+        IShl => out.push(0x78),
+        IShr => out.push(0x7a),
+        INeg => out.push(0x74),
+        IInc(i, n) if i <= 0xff => out.extend_from_slice(&[0x84, i as u8, n as u8]),
+        IInc(i, n) => {
+            out.extend_from_slice(&[0xc4, 0x84]); // wide
+            put_u2(out, i as u16);
+            put_u2(out, i16::from(n) as u16);
+        }
+        Dup => out.push(0x59),
+        Swap => out.push(0x5f),
+        GetStatic => {
+            let index = pool.field_ref("java/lang/System", "out", "Ljava/io/PrintStream;");
+            out.push(0xb2);
+            put_u2(out, index);
+        }
+        InvokeVirtual => {
+            let index = pool.method_ref("java/io/PrintStream", "println", "(I)V");
+            out.push(0xb6);
+            put_u2(out, index);
+        }
+        InvokeStatic(name, arity) => {
+            let index = pool.method_ref(class_name, name, &jvm::descriptor(arity));
+            out.push(0xb8);
+            put_u2(out, index);
+        }
+        IReturn => out.push(0xac),
+        Label(label) => {
+            jumps.labels.insert(label, out.len());
+        }
+        Goto(label) => jumps.encode(if wide { 0xc8 } else { 0xa7 }, label, wide, out), // goto_w, goto
+        IfICmp(relation, label) => {
+            let opcode = match relation {
+                Relation::Eq => 0x9f,
+                Relation::Ne => 0xa0,
+                Relation::Lt => 0xa1,
+                Relation::Ge => 0xa2,
+                Relation::Gt => 0xa3,
+                Relation::Le => 0xa4,
+            };
+            jumps.encode(opcode, label, false, out);
+        }
+    }
+}
+
+fn encode_local(opcode: u8, index: u16, out: &mut Vec<u8>) {
+    if index <= 0xff {
+        out.extend_from_slice(&[opcode, index as u8]);
+    } else {
+        out.extend_from_slice(&[0xc4, opcode]); // wide
+        put_u2(out, index);
+    }
+}
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+enum Constant {
+    Utf8(String),
+    Integer(i32),
+    Class(u16),
+    NameAndType(u16, u16),
+    FieldRef(u16, u16),
+    MethodRef(u16, u16),
+}
+
+/// Constant pool deduplicating entries. Indices start from 1 as in the class file.
+struct ConstantPool {
+    entries: Vec<Constant>,
+    indices: HashMap<Constant, u16>,
+}
+
+impl ConstantPool {
+    fn new() -> ConstantPool {
+        ConstantPool {
+            entries: vec![],
+            indices: HashMap::new(),
+        }
+    }
+
+    fn add(&mut self, constant: Constant) -> u16 {
+        if let Some(index) = self.indices.get(&constant) {
+            return *index;
+        }
+
+        self.entries.push(constant.clone());
+        let index = self.entries.len() as u16;
+        self.indices.insert(constant, index);
+
+        index
+    }
+
+    fn utf8(&mut self, value: &str) -> u16 {
+        self.add(Constant::Utf8(value.to_string()))
+    }
+
+    fn integer(&mut self, value: i32) -> u16 {
+        self.add(Constant::Integer(value))
+    }
+
+    fn class(&mut self, name: &str) -> u16 {
+        let name = self.utf8(name);
+        self.add(Constant::Class(name))
+    }
+
+    fn name_and_type(&mut self, name: &str, descriptor: &str) -> u16 {
+        let (name, descriptor) = (self.utf8(name), self.utf8(descriptor));
+        self.add(Constant::NameAndType(name, descriptor))
+    }
+
+    fn field_ref(&mut self, class: &str, name: &str, descriptor: &str) -> u16 {
+        let class = self.class(class);
+        let name_and_type = self.name_and_type(name, descriptor);
+        self.add(Constant::FieldRef(class, name_and_type))
+    }
+
+    fn method_ref(&mut self, class: &str, name: &str, descriptor: &str) -> u16 {
+        let class = self.class(class);
+        let name_and_type = self.name_and_type(name, descriptor);
+        self.add(Constant::MethodRef(class, name_and_type))
+    }
+
+    fn write(&self, out: &mut Vec<u8>) {
+        put_u2(out, self.entries.len() as u16 + 1);
+
+        for entry in self.entries.iter() {
+            use Constant::*;
+            match entry {
+                Utf8(value) => {
+                    // Modified UTF-8 differs from standard one only for '\0' and supplementary
+                    // characters, which don't appear in names and descriptors we emit.
+                    out.push(1);
+                    put_u2(out, value.len() as u16);
+                    out.extend_from_slice(value.as_bytes());
+                }
+                Integer(value) => {
+                    out.push(3);
+                    put_u4(out, *value as u32);
+                }
+                Class(name) => {
+                    out.push(7);
+                    put_u2(out, *name);
+                }
+                NameAndType(name, descriptor) => {
+                    out.push(12);
+                    put_u2(out, *name);
+                    put_u2(out, *descriptor);
+                }
+                FieldRef(class, name_and_type) => {
+                    out.push(9);
+                    put_u2(out, *class);
+                    put_u2(out, *name_and_type);
+                }
+                MethodRef(class, name_and_type) => {
+                    out.push(10);
+                    put_u2(out, *class);
+                    put_u2(out, *name_and_type);
+                }
+            }
+        }
+    }
+}
+
+fn put_u2(out: &mut Vec<u8>, value: u16) {
+    out.extend_from_slice(&value.to_be_bytes());
+}
+
+fn put_u4(out: &mut Vec<u8>, value: u32) {
+    out.extend_from_slice(&value.to_be_bytes());
+}
@@ -0,0 +1,88 @@
+//! Command line handling shared by the `insc_*` binaries.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::{self, Command};
+
+use crate::ast::Stmt;
+use crate::{opt, CompileError};
+
+/// Source file named by the first argument which is not a flag, and flags given with it.
+pub struct Invocation {
+    pub filename: String,
+    pub contents: String,
+    flags: Vec<String>,
+}
+
+impl Invocation {
+    /// Reads arguments of the process and the source file they name.
+    pub fn from_args() -> Invocation {
+        let (flags, files): (Vec<String>, Vec<String>) =
+            env::args().skip(1).partition(|arg| arg.starts_with('-'));
+
+        let filename = files
+            .into_iter()
+            .next()
+            .expect("No filename present. Please specify filename as argument");
+        let contents = fs::read_to_string(&filename).expect("Error reading file");
+
+        Invocation { filename, contents, flags }
+    }
+
+    pub fn flag(&self, flag: &str) -> bool {
+        self.flags.iter().any(|arg| arg == flag)
+    }
+
+    /// Parsed and checked program, exiting on errors. `-O` runs AST optimisations on it, and with
+    /// `--stats` the number of dead stores they removed is reported.
+    #[allow(clippy::vec_box)]
+    pub fn program(&self) -> Vec<Box<Stmt>> {
+        let stmts = crate::parse_and_check(&self.contents).unwrap_or_else(|errors| self.fail(&errors));
+        if !self.flag("-O") {
+            return stmts;
+        }
+
+        let (stmts, removed) = opt::optimize(stmts);
+        if self.flag("--stats") {
+            eprintln!("dead stores: {} removed", removed);
+        }
+
+        stmts
+    }
+
+    /// Prints errors pointing into the source and exits with status 1.
+    pub fn fail(&self, errors: &[CompileError]) -> ! {
+        errors
+            .iter()
+            .for_each(|error| eprint!("{}", error.render(&self.filename, &self.contents)));
+        process::exit(1);
+    }
+
+    pub fn file_stem(&self) -> &str {
+        let path = Path::new(&self.filename);
+        path.file_stem().expect("Unable to get file stem").to_str().unwrap()
+    }
+
+    /// Directory of the source file, `""` when it is in the current one.
+    pub fn parent(&self) -> &str {
+        let path = Path::new(&self.filename);
+        path.parent().unwrap().to_str().expect("Error getting parent")
+    }
+
+    /// Path next to the source file with `suffix` in place of its extension, e.g. `foo/bar/test01.ll`.
+    pub fn output_path(&self, suffix: &str) -> String {
+        match self.parent() {
+            "" => format!("{}{}", self.file_stem(), suffix),
+            parent => format!("{}/{}{}", parent, self.file_stem(), suffix),
+        }
+    }
+}
+
+/// Runs external tool, waiting for it to finish.
+pub fn run_tool(program: &str, args: &[&str]) {
+    Command::new(program)
+        .args(args)
+        .output()
+        .unwrap_or_else(|_| panic!("failed to execute {}", program));
+}
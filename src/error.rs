@@ -0,0 +1,192 @@
+use std::cmp;
+use std::error::Error;
+use std::fmt;
+use std::iter;
+
+use crate::ast::Span;
+use crate::instant_parser::Token;
+
+#[derive(Debug, PartialEq)]
+pub enum ErrorKind {
+    Syntax(String),
+    IntegerOverflow(String),
+    UndeclaredVariable(String),
+    UndefinedFunction(String),
+    ArgumentCount(String, usize, usize), // function, parameters, arguments
+    DuplicateFunction(String),
+    DuplicateParameter(String),
+    MissingReturn(String),
+    ReturnOutsideFunction,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use ErrorKind::*;
+        match self {
+            Syntax(message) => write!(f, "{}", message),
+            IntegerOverflow(literal) => write!(f, "integer literal {} does not fit in i32", literal),
+            UndeclaredVariable(ident) => write!(f, "use of undeclared variable `{}`", ident),
+            UndefinedFunction(name) => write!(f, "call to undefined function `{}`", name),
+            ArgumentCount(name, params, args) => write!(
+                f,
+                "function `{}` takes {} argument{} but {} {} supplied",
+                name,
+                params,
+                if *params == 1 { "" } else { "s" },
+                args,
+                if *args == 1 { "was" } else { "were" }
+            ),
+            DuplicateFunction(name) => write!(f, "function `{}` is defined more than once", name),
+            DuplicateParameter(name) => write!(f, "parameter `{}` is declared more than once", name),
+            MissingReturn(name) => write!(f, "function `{}` may end without returning a value", name),
+            ReturnOutsideFunction => write!(f, "`return` outside of function"),
+        }
+    }
+}
+
+/// Error reported by parser or backends, pointing to the offending part of source code.
+#[derive(Debug, PartialEq)]
+pub struct CompileError {
+    pub kind: ErrorKind,
+    pub span: Span,
+}
+
+impl CompileError {
+    pub fn new(kind: ErrorKind, span: Span) -> CompileError {
+        CompileError { kind, span }
+    }
+
+    /// 1-based line and column of the start of the span.
+    pub fn line_col(&self, source: &str) -> (usize, usize) {
+        let start = char_boundary(self.span.start, source);
+        let before = &source[..start];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+        (line, source[line_start..start].chars().count() + 1)
+    }
+
+    /// Renders error with the offending line and a caret under the span, e.g.
+    ///
+    /// ```text
+    /// error: use of undeclared variable `x`
+    ///  --> test.ins:2:5
+    ///   |
+    /// 2 | a + x
+    ///   |     ^
+    /// ```
+    ///
+    /// Caret is padded with tabs where the source line has them, so it lines up in any tab width.
+    pub fn render(&self, filename: &str, source: &str) -> String {
+        let (line, col) = self.line_col(source);
+        let line_text = source.lines().nth(line - 1).unwrap_or("");
+        let line_number = line.to_string();
+        let gutter = " ".repeat(line_number.len());
+
+        let start = char_boundary(self.span.start, source);
+        let end = char_boundary(self.span.end, source);
+        let underlined = source[start..end].lines().next().unwrap_or("");
+        let carets = "^".repeat(cmp::max(underlined.chars().count(), 1));
+        let padding: String = line_text
+            .chars()
+            .chain(iter::repeat(' '))
+            .take(col - 1)
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+
+        format!(
+            "error: {}\n{}--> {}:{}:{}\n{} |\n{} | {}\n{} | {}{}\n",
+            self.kind,
+            gutter,
+            filename,
+            line,
+            col,
+            gutter,
+            line_number,
+            line_text,
+            gutter,
+            padding,
+            carets
+        )
+    }
+}
+
+/// Clamps byte offset to source length and moves it back to the nearest char boundary.
+fn char_boundary(offset: usize, source: &str) -> usize {
+    let mut offset = cmp::min(offset, source.len());
+    while !source.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    offset
+}
+
+impl fmt::Display for CompileError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.kind)
+    }
+}
+
+impl Error for CompileError {}
+
+type LalrpopError<'input> = lalrpop_util::ParseError<usize, Token<'input>, CompileError>;
+
+impl<'input> From<LalrpopError<'input>> for CompileError {
+    fn from(error: LalrpopError<'input>) -> CompileError {
+        use lalrpop_util::ParseError::*;
+
+        match error {
+            InvalidToken { location } => CompileError::new(
+                ErrorKind::Syntax(String::from("invalid token")),
+                Span::new(location, location + 1),
+            ),
+            UnrecognizedEOF { location, expected } => CompileError::new(
+                ErrorKind::Syntax(format!(
+                    "unexpected end of file{}",
+                    format_expected(&expected)
+                )),
+                Span::new(location, location),
+            ),
+            UnrecognizedToken {
+                token: (start, Token(_, text), end),
+                expected,
+            } => CompileError::new(
+                ErrorKind::Syntax(format!(
+                    "unexpected token `{}`{}",
+                    text,
+                    format_expected(&expected)
+                )),
+                Span::new(start, end),
+            ),
+            ExtraToken {
+                token: (start, Token(_, text), end),
+            } => CompileError::new(
+                ErrorKind::Syntax(format!("extra token `{}`", text)),
+                Span::new(start, end),
+            ),
+            User { error } => error,
+        }
+    }
+}
+
+fn format_expected(expected: &[String]) -> String {
+    let expected: Vec<String> = expected.iter().map(|terminal| describe_terminal(terminal)).collect();
+
+    match expected.len() {
+        0 => String::new(),
+        1 => format!(", expected {}", expected[0]),
+        _ => format!(", expected one of {}", expected.join(", ")),
+    }
+}
+
+/// Lalrpop names terminals as they're written in the grammar: regular expressions of literals and
+/// identifiers get names instead, quoted tokens are shown like the unexpected one.
+fn describe_terminal(terminal: &str) -> String {
+    match terminal {
+        r##"r#"[0-9]+"#"## => String::from("number"),
+        r##"r#"[[:alpha:]]\\w*"#"## => String::from("identifier"),
+        _ => match terminal.strip_prefix('"').and_then(|token| token.strip_suffix('"')) {
+            Some(token) => format!("`{}`", token),
+            None => terminal.to_string(),
+        },
+    }
+}
@@ -1,61 +1,44 @@
-use std::env;
 use std::fs;
-use std::path::Path;
-use std::process::Command;
-
-pub mod ast;
-pub mod instant_parser;
-pub mod jvm;
-
-
-/*
-tests=(01 02 03 04 05 06 07)
-
-for x in ${tests[*]}; do
-    ./insc_jvm foo/bar/test$x.ins
-done
-
-cd foo/bar;
-
-for x in ${tests[*]}; do
-	java test$x
-done
-
-cd ../..
-*/
 
+use instant::cli::{self, Invocation};
+use instant::jvm;
 
 fn main() {
-    let args: Vec<String> = env::args().collect();
-
-    let filename = args
-        .get(1)
-        .expect("No filename present. Please specify filename as argument");
-
-    let contents = fs::read_to_string(filename).expect("Error reading file");
-
-    let stmts = instant_parser::StmtsParser::new().parse(&contents).unwrap();
-
-    let path = Path::new(filename);
-    let file_stem = path.file_stem().expect("Unable to get file stem").to_str().unwrap();
-    let parent = path.parent().unwrap().to_str().expect("Error getting parent");
-
-    let generated_code_path = match parent {
-        "" => format!("{}.j", file_stem),
-        parent_str => format!("{}/{}.j", parent_str, file_stem)
-    };
-
-    let code = jvm::compile(&stmts, &file_stem);
-
-    fs::write(&generated_code_path, code).expect("Unable to write to file");
-
-    let args = match parent {
-        "" => ["-jar", "lib/jasmin.jar", "-d", ".", &generated_code_path],
-        parent_str => ["-jar", "lib/jasmin.jar", "-d", &parent_str, &generated_code_path]
+    let invocation = Invocation::from_args();
+
+    // --direct writes .class file without Jasmin, so no Java installation is needed
+    let direct = invocation.flag("--direct");
+    // --stats reports instruction count of all methods together before and after peephole
+    // optimisation, and with -O also number of dead stores removed
+    let stats = invocation.flag("--stats");
+
+    let generated_code_path = invocation.output_path(".j");
+    let generated_class_path = invocation.output_path(".class");
+    let class_name = invocation.file_stem();
+
+    let program = jvm::lower(&invocation.program());
+    let compiled = jvm::Class::new(&program);
+
+    if stats {
+        let peephole_stats = compiled.peephole_stats();
+        eprintln!(
+            "peephole: {} -> {} instructions",
+            peephole_stats.before, peephole_stats.after
+        );
+    }
+
+    fs::write(&generated_code_path, compiled.jasmin(class_name)).expect("Unable to write to file");
+
+    if direct {
+        fs::write(&generated_class_path, compiled.class_file(class_name))
+            .expect("Unable to write to file");
+        return;
+    }
+
+    let output_dir = match invocation.parent() {
+        "" => ".",
+        parent => parent,
     };
 
-    Command::new("java")
-        .args(&args)
-        .output()
-        .expect("failed to execute java/jasmin");
+    cli::run_tool("java", &["-jar", "lib/jasmin.jar", "-d", output_dir, &generated_code_path]);
 }
@@ -0,0 +1,32 @@
+use std::process;
+
+use instant::cli::Invocation;
+use instant::interp::Interpreter;
+use instant::ir;
+
+fn main() {
+    let invocation = Invocation::from_args();
+
+    // --dump-ir prints three-address code consumed by the backends instead of running the program
+    let dump_ir = invocation.flag("--dump-ir");
+
+    let stmts = invocation.program();
+
+    if dump_ir {
+        print!("{}", ir::eliminate_common_subexpressions(ir::lower(&stmts)));
+        return;
+    }
+
+    let mut interpreter = Interpreter::new();
+    let result = interpreter.run(&stmts);
+
+    interpreter
+        .output()
+        .iter()
+        .for_each(|value| println!("{}", value));
+
+    if let Err(error) = result {
+        eprintln!("runtime error: {}", error);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,23 @@
+use std::fs;
+
+use instant::cli::Invocation;
+use instant::wasm;
+
+/*
+Generated module imports `env.printInt` and exports `main`, e.g. in node:
+
+const bytes = require('fs').readFileSync('foo/bar/test01.wasm');
+WebAssembly.instantiate(bytes, { env: { printInt: console.log } })
+    .then(({ instance }) => instance.exports.main());
+*/
+
+fn main() {
+    let invocation = Invocation::from_args();
+
+    let stmts = invocation.program();
+
+    fs::write(invocation.output_path(".wat"), wasm::compile_wat(&stmts))
+        .expect("Unable to write to file");
+    fs::write(invocation.output_path(".wasm"), wasm::compile_wasm(&stmts))
+        .expect("Unable to write to file");
+}
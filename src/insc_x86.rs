@@ -0,0 +1,17 @@
+use std::fs;
+
+use instant::cli::{self, Invocation};
+use instant::x86;
+
+fn main() {
+    let invocation = Invocation::from_args();
+
+    let code = x86::compile(&invocation.program());
+
+    let generated_code_path = invocation.output_path(".s");
+    let generated_executable_path = invocation.output_path("");
+
+    fs::write(&generated_code_path, code).expect("Unable to write to file");
+
+    cli::run_tool("gcc", &["-o", &generated_executable_path, &generated_code_path]);
+}
@@ -0,0 +1,358 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::mem;
+use std::sync::Arc;
+
+use crate::ast::{self, Cond, Expr, Function, Opcode, Relation, Stmt};
+
+/// Deepest call nesting before `StackOverflow`; compiled programs get at least as deep on x86 and LLVM
+const MAX_CALL_DEPTH: usize = 100_000;
+
+/// Runs program and returns printed values. Arithmetic wraps around on overflow,
+/// the same as `i32` arithmetic in LLVM and JVM.
+pub fn run(stmts: &[Box<Stmt>]) -> Result<Vec<i32>, RuntimeError> {
+    let mut interpreter = Interpreter::new();
+
+    interpreter.run(stmts)?;
+
+    Ok(interpreter.output)
+}
+
+#[derive(Debug, PartialEq)]
+pub enum RuntimeError {
+    DivisionByZero,
+    UndeclaredVariable(String),
+    UndefinedFunction(String),
+    MissingReturn(String),
+    StackOverflow,
+}
+
+impl fmt::Display for RuntimeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use RuntimeError::*;
+        match self {
+            DivisionByZero => write!(f, "division by zero"),
+            UndeclaredVariable(ident) => write!(f, "use of undeclared variable `{}`", ident),
+            UndefinedFunction(name) => write!(f, "call to undefined function `{}`", name),
+            MissingReturn(name) => write!(f, "function `{}` ended without returning a value", name),
+            StackOverflow => write!(f, "calls nested more than {} deep", MAX_CALL_DEPTH),
+        }
+    }
+}
+
+impl Error for RuntimeError {}
+
+/// Tree-walking interpreter keeping variable values and everything printed so far,
+/// so that output produced before a runtime error is still available.
+/// Each call gets fresh variables, restored when the call returns.
+pub struct Interpreter {
+    vars: HashMap<String, i32>,
+    functions: HashMap<String, Arc<Function>>,
+    output: Vec<i32>,
+}
+
+/// Work left to do; the task on top of the stack runs next and may push more tasks in its place.
+/// Tasks after `Eval` and `EvalCond` find operands they need on the value and condition stacks.
+enum Task<'a> {
+    Exec(&'a [Box<Stmt>]), // statements left in block
+    Eval(&'a Expr),
+    EvalCond(&'a Cond),
+    Assign(&'a str),
+    Print,
+    Op(Opcode),
+    Neg,
+    Call(&'a str, usize), // arguments already evaluated
+    Return,
+    Compare(Relation),
+    And(&'a Cond), // right operand, evaluated when left one holds
+    Or(&'a Cond),  // right operand, evaluated when left one doesn't hold
+    Not,
+    Branch(&'a [Box<Stmt>], &'a [Box<Stmt>]),
+    Loop(&'a Cond, &'a [Box<Stmt>]), // runs body when condition holds, then checks it again
+    FunctionEnd(&'a str),            // body of called function ran off its end
+}
+
+/// Call in progress, restored by `return`
+struct Frame {
+    caller_vars: HashMap<String, i32>,
+    tasks: usize, // caller's tasks, below the callee's
+}
+
+/// Explicit stacks of the program being run, so that deep recursion in Instant uses heap memory
+/// rather than interpreter's own stack.
+struct Machine<'a> {
+    functions: &'a HashMap<String, Arc<Function>>,
+    tasks: Vec<Task<'a>>,
+    values: Vec<i32>,
+    conditions: Vec<bool>,
+    frames: Vec<Frame>,
+}
+
+impl Interpreter {
+    pub fn new() -> Interpreter {
+        Interpreter {
+            vars: HashMap::new(),
+            functions: HashMap::new(),
+            output: vec![],
+        }
+    }
+
+    pub fn output(&self) -> &[i32] {
+        &self.output
+    }
+
+    /// Functions are declared before anything runs, so they can be called above their declaration
+    pub fn run(&mut self, stmts: &[Box<Stmt>]) -> Result<(), RuntimeError> {
+        for function in ast::functions(stmts) {
+            self.functions
+                .entry(function.name.clone())
+                .or_insert_with(|| Arc::new(function.clone()));
+        }
+
+        let functions = self.functions.clone();
+        let mut machine = Machine {
+            functions: &functions,
+            tasks: vec![Task::Exec(stmts)],
+            values: vec![],
+            conditions: vec![],
+            frames: vec![],
+        };
+
+        while let Some(task) = machine.tasks.pop() {
+            if let Err(error) = self.step(task, &mut machine) {
+                // Variables of the program itself stay, as when it ends normally
+                if let Some(frame) = machine.frames.into_iter().next() {
+                    self.vars = frame.caller_vars;
+                }
+                return Err(error);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn step<'a>(&mut self, task: Task<'a>, machine: &mut Machine<'a>) -> Result<(), RuntimeError> {
+        let tasks = &mut machine.tasks;
+
+        match task {
+            Task::Exec([]) => {}
+            Task::Exec([stmt, rest @ ..]) => {
+                tasks.push(Task::Exec(rest));
+                exec_stmt(stmt, tasks);
+            }
+            Task::Eval(expr) => self.eval_expr(expr, machine)?,
+            Task::EvalCond(cond) => eval_cond(cond, tasks),
+            Task::Assign(ident) => {
+                let value = machine.values.pop().unwrap();
+                self.vars.insert(ident.to_string(), value);
+            }
+            Task::Print => self.output.push(machine.values.pop().unwrap()),
+            Task::Op(opcode) => {
+                let r = machine.values.pop().unwrap();
+                let l = machine.values.pop().unwrap();
+                machine.values.push(eval_op(opcode, l, r)?);
+            }
+            Task::Neg => {
+                let value = machine.values.pop().unwrap();
+                machine.values.push(value.wrapping_neg());
+            }
+            Task::Call(name, args) => self.call(name, args, machine)?,
+            Task::Return => self.ret(machine),
+            Task::Compare(relation) => {
+                let r = machine.values.pop().unwrap();
+                let l = machine.values.pop().unwrap();
+                machine.conditions.push(relation.holds(l, r));
+            }
+            Task::And(r_cond) => {
+                if *machine.conditions.last().unwrap() {
+                    machine.conditions.pop();
+                    eval_cond(r_cond, tasks);
+                }
+            }
+            Task::Or(r_cond) => {
+                if !*machine.conditions.last().unwrap() {
+                    machine.conditions.pop();
+                    eval_cond(r_cond, tasks);
+                }
+            }
+            Task::Not => {
+                let holds = machine.conditions.pop().unwrap();
+                machine.conditions.push(!holds);
+            }
+            Task::Branch(then, otherwise) => {
+                let branch = if machine.conditions.pop().unwrap() {
+                    then
+                } else {
+                    otherwise
+                };
+                tasks.push(Task::Exec(branch));
+            }
+            Task::Loop(cond, body) => {
+                if machine.conditions.pop().unwrap() {
+                    tasks.push(Task::Loop(cond, body));
+                    eval_cond(cond, tasks);
+                    tasks.push(Task::Exec(body));
+                }
+            }
+            Task::FunctionEnd(name) => return Err(RuntimeError::MissingReturn(name.to_string())),
+        }
+
+        Ok(())
+    }
+
+    fn call<'a>(
+        &mut self,
+        name: &str,
+        args: usize,
+        machine: &mut Machine<'a>,
+    ) -> Result<(), RuntimeError> {
+        let function = match machine.functions.get(name) {
+            Some(function) => function,
+            None => return Err(RuntimeError::UndefinedFunction(name.to_string())),
+        };
+
+        if machine.frames.len() == MAX_CALL_DEPTH {
+            return Err(RuntimeError::StackOverflow);
+        }
+
+        let values = machine.values.split_off(machine.values.len() - args);
+        let vars = function.params.iter().cloned().zip(values).collect();
+
+        machine.frames.push(Frame {
+            caller_vars: mem::replace(&mut self.vars, vars),
+            tasks: machine.tasks.len(),
+        });
+        machine.tasks.push(Task::FunctionEnd(&function.name));
+        machine.tasks.push(Task::Exec(&function.body));
+
+        Ok(())
+    }
+
+    /// Drops what's left of the callee and hands its value to the caller; `return` outside of
+    /// function ends the program
+    fn ret(&mut self, machine: &mut Machine) {
+        let value = machine.values.pop().unwrap();
+
+        match machine.frames.pop() {
+            Some(frame) => {
+                machine.tasks.truncate(frame.tasks);
+                self.vars = frame.caller_vars;
+                machine.values.push(value);
+            }
+            None => machine.tasks.clear(),
+        }
+    }
+
+    /// Arguments are evaluated before the function is looked up
+    fn eval_expr<'a>(
+        &mut self,
+        expr: &'a Expr,
+        machine: &mut Machine<'a>,
+    ) -> Result<(), RuntimeError> {
+        let tasks = &mut machine.tasks;
+
+        match expr {
+            Expr::Number(n) => machine.values.push(*n),
+            Expr::Ident(ident, _) => {
+                let value = self
+                    .vars
+                    .get(ident)
+                    .copied()
+                    .ok_or_else(|| RuntimeError::UndeclaredVariable(ident.clone()))?;
+                machine.values.push(value);
+            }
+            Expr::Op(l_expr, opcode, r_expr) => {
+                tasks.push(Task::Op(*opcode));
+                tasks.push(Task::Eval(r_expr));
+                tasks.push(Task::Eval(l_expr));
+            }
+            Expr::Neg(expr) => {
+                tasks.push(Task::Neg);
+                tasks.push(Task::Eval(expr));
+            }
+            Expr::Call(name, args, _) => {
+                tasks.push(Task::Call(name, args.len()));
+                tasks.extend(args.iter().rev().map(|arg| Task::Eval(arg)));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Tasks are pushed in reverse, the one pushed last runs first
+fn exec_stmt<'a>(stmt: &'a Stmt, tasks: &mut Vec<Task<'a>>) {
+    match stmt {
+        Stmt::SAss(ident, expr) => {
+            tasks.push(Task::Assign(ident));
+            tasks.push(Task::Eval(expr));
+        }
+        Stmt::SExpr(expr) => {
+            tasks.push(Task::Print);
+            tasks.push(Task::Eval(expr));
+        }
+        Stmt::SIf(cond, then, otherwise) => {
+            tasks.push(Task::Branch(then, otherwise));
+            eval_cond(cond, tasks);
+        }
+        Stmt::SWhile(cond, body) => {
+            tasks.push(Task::Loop(cond, body));
+            eval_cond(cond, tasks);
+        }
+        Stmt::SFn(_) => {}
+        Stmt::SReturn(expr, _) => {
+            tasks.push(Task::Return);
+            tasks.push(Task::Eval(expr));
+        }
+    }
+}
+
+/// `&&` and `||` short-circuit, so their right operand can't fail when it isn't needed
+fn eval_cond<'a>(cond: &'a Cond, tasks: &mut Vec<Task<'a>>) {
+    match cond {
+        Cond::Cmp(l_expr, relation, r_expr) => {
+            tasks.push(Task::Compare(*relation));
+            tasks.push(Task::Eval(r_expr));
+            tasks.push(Task::Eval(l_expr));
+        }
+        Cond::And(l_cond, r_cond) => {
+            tasks.push(Task::And(r_cond));
+            tasks.push(Task::EvalCond(l_cond));
+        }
+        Cond::Or(l_cond, r_cond) => {
+            tasks.push(Task::Or(r_cond));
+            tasks.push(Task::EvalCond(l_cond));
+        }
+        Cond::Not(cond) => {
+            tasks.push(Task::Not);
+            tasks.push(Task::EvalCond(cond));
+        }
+    }
+}
+
+impl Default for Interpreter {
+    fn default() -> Interpreter {
+        Interpreter::new()
+    }
+}
+
+/// Evaluates single operation with wrap-around semantics. `i32::MIN / -1` gives `i32::MIN` and
+/// `i32::MIN % -1` gives 0 as on JVM; shift amount is taken modulo 32.
+pub fn eval_op(opcode: Opcode, l: i32, r: i32) -> Result<i32, RuntimeError> {
+    use Opcode::*;
+
+    match opcode {
+        Add => Ok(l.wrapping_add(r)),
+        Sub => Ok(l.wrapping_sub(r)),
+        Mul => Ok(l.wrapping_mul(r)),
+        Div | Rem if r == 0 => Err(RuntimeError::DivisionByZero),
+        Div => Ok(l.wrapping_div(r)),
+        Rem => Ok(l.wrapping_rem(r)),
+        And => Ok(l & r),
+        Or => Ok(l | r),
+        Xor => Ok(l ^ r),
+        Shl => Ok(l.wrapping_shl(r as u32)),
+        Shr => Ok(l.wrapping_shr(r as u32)),
+    }
+}
@@ -0,0 +1,539 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::ast::{self, Cond, Expr, Opcode, Relation, Stmt};
+
+/// Three-address code shared by the LLVM and JVM backends.
+///
+/// Every operation reads at most two operands and writes a fresh temporary, so the program is a flat
+/// list of instructions passes can scan without recursion. Temporaries are assigned exactly once;
+/// variables are assigned by `Assign` and may be reassigned any number of times.
+///
+/// Control flow is explicit: `Label` starts a basic block and is always preceded by `Jump`,
+/// `Branch` or `Return`, so no block falls through into the next one. A temporary is only read in
+/// the block which computes it.
+///
+/// Each function gets its own body with separate temporaries, labels and variables; parameters are
+/// variables assigned on entry. Every path through a function body ends with `Return`.
+pub struct Program {
+    pub functions: Vec<Function>,
+    pub main: Body,
+}
+
+pub struct Function {
+    pub name: String,
+    pub params: Vec<String>,
+    pub body: Body,
+}
+
+pub struct Body {
+    pub instructions: Vec<Instruction>,
+    pub temp_count: usize,
+    pub label_count: usize,
+}
+
+/// Compiler generated value, printed as `%N` so it never clashes with a variable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Temp(pub usize);
+
+/// Jump target, printed as `LN`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Label(pub usize);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Operand {
+    Const(i32),
+    Temp(Temp),
+    Var(String),
+}
+
+#[derive(Debug, Clone)]
+pub enum Instruction {
+    BinOp {
+        dest: Temp,
+        opcode: Opcode,
+        l: Operand,
+        r: Operand,
+    },
+    Neg {
+        dest: Temp,
+        value: Operand,
+    },
+    Assign {
+        dest: String,
+        value: Operand,
+    },
+    Print(Operand),
+    /// Arguments are already evaluated left to right, the call itself may print
+    Call {
+        dest: Temp,
+        function: String,
+        args: Vec<Operand>,
+    },
+    Return(Operand),
+    Label(Label),
+    Jump(Label),
+    /// Jumps to `then` when `l relation r` holds and to `otherwise` when it doesn't
+    Branch {
+        relation: Relation,
+        l: Operand,
+        r: Operand,
+        then: Label,
+        otherwise: Label,
+    },
+}
+
+impl Instruction {
+    /// Operands read by instruction
+    pub fn operands(&self) -> Vec<&Operand> {
+        match self {
+            Instruction::BinOp { l, r, .. } | Instruction::Branch { l, r, .. } => vec![l, r],
+            Instruction::Neg { value, .. }
+            | Instruction::Assign { value, .. }
+            | Instruction::Print(value)
+            | Instruction::Return(value) => vec![value],
+            Instruction::Call { args, .. } => args.iter().collect(),
+            Instruction::Label(_) | Instruction::Jump(_) => vec![],
+        }
+    }
+
+    /// Labels instruction may jump to
+    pub fn targets(&self) -> Vec<Label> {
+        match self {
+            Instruction::Jump(label) => vec![*label],
+            Instruction::Branch { then, otherwise, .. } => vec![*then, *otherwise],
+            _ => vec![],
+        }
+    }
+}
+
+/// Lowers statements to three-address code. Program must have passed `semantic::check`, so every
+/// variable is defined whenever it is read, calls match declared functions and function bodies
+/// return on every path. Statements after `return` are dropped.
+pub fn lower(stmts: &[Box<Stmt>]) -> Program {
+    let functions = ast::functions(stmts)
+        .map(|function| {
+            let mut lowering = Lowering::new();
+            lowering.lower_stmts(&function.body);
+
+            Function {
+                name: function.name.clone(),
+                params: function.params.clone(),
+                body: lowering.body,
+            }
+        })
+        .collect();
+
+    let mut lowering = Lowering::new();
+    lowering.lower_stmts(stmts);
+
+    Program {
+        functions,
+        main: lowering.body,
+    }
+}
+
+struct Lowering {
+    body: Body,
+    reachable: bool, // false after `return` until the next label
+}
+
+impl Lowering {
+    fn new() -> Lowering {
+        Lowering {
+            body: Body {
+                instructions: vec![],
+                temp_count: 0,
+                label_count: 0,
+            },
+            reachable: true,
+        }
+    }
+
+    fn push(&mut self, instruction: Instruction) {
+        self.body.instructions.push(instruction);
+    }
+
+    fn new_temp(&mut self) -> Temp {
+        self.body.temp_count += 1;
+
+        Temp(self.body.temp_count - 1)
+    }
+
+    fn new_label(&mut self) -> Label {
+        self.body.label_count += 1;
+
+        Label(self.body.label_count - 1)
+    }
+
+    fn lower_stmts(&mut self, stmts: &[Box<Stmt>]) {
+        for stmt in stmts.iter() {
+            if !self.reachable {
+                break;
+            }
+
+            match stmt.as_ref() {
+                Stmt::SAss(ident, expr) => {
+                    let value = self.lower_expr(expr);
+                    self.push(Instruction::Assign {
+                        dest: ident.clone(),
+                        value,
+                    });
+                }
+                Stmt::SExpr(expr) => {
+                    let value = self.lower_expr(expr);
+                    self.push(Instruction::Print(value));
+                }
+                Stmt::SIf(cond, then, otherwise) => self.lower_if(cond, then, otherwise),
+                Stmt::SWhile(cond, body) => self.lower_while(cond, body),
+                Stmt::SFn(_) => {}
+                Stmt::SReturn(expr, _) => {
+                    let value = self.lower_expr(expr);
+                    self.push(Instruction::Return(value));
+                    self.reachable = false;
+                }
+            }
+        }
+    }
+
+    /// Without else branch the condition jumps straight past `then`. End label is left out when
+    /// both branches return.
+    fn lower_if(&mut self, cond: &Cond, then: &[Box<Stmt>], otherwise: &[Box<Stmt>]) {
+        let then_label = self.new_label();
+        let end_label = self.new_label();
+        let otherwise_label = if otherwise.is_empty() {
+            end_label
+        } else {
+            self.new_label()
+        };
+
+        self.lower_cond(cond, then_label, otherwise_label);
+
+        self.push(Instruction::Label(then_label));
+        self.lower_stmts(then);
+        let then_reachable = self.end_block(end_label);
+
+        let otherwise_reachable = if otherwise.is_empty() {
+            true
+        } else {
+            self.push(Instruction::Label(otherwise_label));
+            self.reachable = true;
+            self.lower_stmts(otherwise);
+            self.end_block(end_label)
+        };
+
+        if then_reachable || otherwise_reachable {
+            self.push(Instruction::Label(end_label));
+            self.reachable = true;
+        }
+    }
+
+    /// Jumps to label unless block already returned; tells whether it did jump
+    fn end_block(&mut self, label: Label) -> bool {
+        let reachable = self.reachable;
+        if reachable {
+            self.push(Instruction::Jump(label));
+        }
+        self.reachable = false;
+
+        reachable
+    }
+
+    /// Condition is checked in its own block at the loop head, which body jumps back to
+    fn lower_while(&mut self, cond: &Cond, body: &[Box<Stmt>]) {
+        let head_label = self.new_label();
+        let body_label = self.new_label();
+        let end_label = self.new_label();
+
+        self.push(Instruction::Jump(head_label));
+        self.push(Instruction::Label(head_label));
+        self.lower_cond(cond, body_label, end_label);
+
+        self.push(Instruction::Label(body_label));
+        self.lower_stmts(body);
+        self.end_block(head_label);
+
+        self.push(Instruction::Label(end_label));
+        self.reachable = true;
+    }
+
+    /// Jumping code: `&&` and `||` become chains of branches, so the right operand is only
+    /// evaluated when it decides the result.
+    fn lower_cond(&mut self, cond: &Cond, then: Label, otherwise: Label) {
+        match cond {
+            Cond::Cmp(l_expr, relation, r_expr) => {
+                let (l, r) = (self.lower_expr(l_expr), self.lower_expr(r_expr));
+
+                self.push(Instruction::Branch {
+                    relation: *relation,
+                    l,
+                    r,
+                    then,
+                    otherwise,
+                });
+            }
+            Cond::And(l_cond, r_cond) => {
+                let right = self.new_label();
+
+                self.lower_cond(l_cond, right, otherwise);
+                self.push(Instruction::Label(right));
+                self.lower_cond(r_cond, then, otherwise);
+            }
+            Cond::Or(l_cond, r_cond) => {
+                let right = self.new_label();
+
+                self.lower_cond(l_cond, then, right);
+                self.push(Instruction::Label(right));
+                self.lower_cond(r_cond, then, otherwise);
+            }
+            Cond::Not(cond) => self.lower_cond(cond, otherwise, then),
+        }
+    }
+
+    fn lower_expr(&mut self, expr: &Expr) -> Operand {
+        match expr {
+            Expr::Number(n) => Operand::Const(*n),
+            Expr::Ident(ident, _) => Operand::Var(ident.clone()),
+            Expr::Op(l_expr, opcode, r_expr) => {
+                let (l, r) = (self.lower_expr(l_expr), self.lower_expr(r_expr));
+                let dest = self.new_temp();
+
+                self.push(Instruction::BinOp {
+                    dest,
+                    opcode: *opcode,
+                    l,
+                    r,
+                });
+
+                Operand::Temp(dest)
+            }
+            Expr::Neg(expr) => {
+                let value = self.lower_expr(expr);
+                let dest = self.new_temp();
+
+                self.push(Instruction::Neg { dest, value });
+
+                Operand::Temp(dest)
+            }
+            Expr::Call(name, args, _) => {
+                let args = args.iter().map(|arg| self.lower_expr(arg)).collect();
+                let dest = self.new_temp();
+
+                self.push(Instruction::Call {
+                    dest,
+                    function: name.clone(),
+                    args,
+                });
+
+                Operand::Temp(dest)
+            }
+        }
+    }
+}
+
+/// Value numbering within basic blocks: an operation whose operands were already combined
+/// the same way (with no assignment to an operand variable in between) is removed and its
+/// temporary replaced by the earlier one. Commutative operations match with operands swapped.
+/// Nothing is carried over a label, as the earlier computation might not be on every path to it.
+/// Calls are never merged, since each one may print.
+pub fn eliminate_common_subexpressions(program: Program) -> Program {
+    Program {
+        functions: program
+            .functions
+            .into_iter()
+            .map(|function| Function {
+                body: eliminate_in_body(function.body),
+                ..function
+            })
+            .collect(),
+        main: eliminate_in_body(program.main),
+    }
+}
+
+fn eliminate_in_body(body: Body) -> Body {
+    let mut available: HashMap<(Opcode, Operand, Operand), Temp> = HashMap::new();
+    let mut negated: HashMap<Operand, Temp> = HashMap::new();
+    let mut replaced: HashMap<Temp, Temp> = HashMap::new();
+    let mut instructions = vec![];
+
+    let rename = |operand: Operand, replaced: &HashMap<Temp, Temp>| match operand {
+        Operand::Temp(temp) => Operand::Temp(*replaced.get(&temp).unwrap_or(&temp)),
+        operand => operand,
+    };
+
+    for instruction in body.instructions.into_iter() {
+        match instruction {
+            Instruction::BinOp { dest, opcode, l, r } => {
+                let (l, r) = (rename(l, &replaced), rename(r, &replaced));
+                let key = match opcode {
+                    _ if opcode.is_commutative() && operand_order(&r) < operand_order(&l) => {
+                        (opcode, r.clone(), l.clone())
+                    }
+                    _ => (opcode, l.clone(), r.clone()),
+                };
+
+                if let Some(earlier) = available.get(&key) {
+                    replaced.insert(dest, *earlier);
+                    continue;
+                }
+
+                available.insert(key, dest);
+                instructions.push(Instruction::BinOp { dest, opcode, l, r });
+            }
+            Instruction::Neg { dest, value } => {
+                let value = rename(value, &replaced);
+
+                if let Some(earlier) = negated.get(&value) {
+                    replaced.insert(dest, *earlier);
+                    continue;
+                }
+
+                negated.insert(value.clone(), dest);
+                instructions.push(Instruction::Neg { dest, value });
+            }
+            Instruction::Assign { dest, value } => {
+                let variable = Operand::Var(dest.clone());
+                available.retain(|(_, l, r), _| *l != variable && *r != variable);
+                negated.remove(&variable);
+
+                instructions.push(Instruction::Assign {
+                    dest,
+                    value: rename(value, &replaced),
+                });
+            }
+            Instruction::Print(value) => {
+                instructions.push(Instruction::Print(rename(value, &replaced)))
+            }
+            Instruction::Call {
+                dest,
+                function,
+                args,
+            } => instructions.push(Instruction::Call {
+                dest,
+                function,
+                args: args.into_iter().map(|arg| rename(arg, &replaced)).collect(),
+            }),
+            Instruction::Return(value) => {
+                instructions.push(Instruction::Return(rename(value, &replaced)))
+            }
+            Instruction::Label(label) => {
+                available.clear();
+                negated.clear();
+
+                instructions.push(Instruction::Label(label));
+            }
+            Instruction::Jump(label) => instructions.push(Instruction::Jump(label)),
+            Instruction::Branch {
+                relation,
+                l,
+                r,
+                then,
+                otherwise,
+            } => instructions.push(Instruction::Branch {
+                relation,
+                l: rename(l, &replaced),
+                r: rename(r, &replaced),
+                then,
+                otherwise,
+            }),
+        }
+    }
+
+    Body {
+        instructions,
+        temp_count: body.temp_count,
+        label_count: body.label_count,
+    }
+}
+
+/// Arbitrary total order on operands used to normalise commutative operations
+fn operand_order(operand: &Operand) -> (u8, i64, &str) {
+    match operand {
+        Operand::Const(n) => (0, i64::from(*n), ""),
+        Operand::Temp(Temp(index)) => (1, *index as i64, ""),
+        Operand::Var(ident) => (2, 0, ident),
+    }
+}
+
+impl fmt::Display for Temp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "%{}", self.0)
+    }
+}
+
+impl fmt::Display for Label {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "L{}", self.0)
+    }
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Operand::Const(n) => write!(f, "{}", n),
+            Operand::Temp(temp) => write!(f, "{}", temp),
+            Operand::Var(ident) => write!(f, "{}", ident),
+        }
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Instruction::BinOp { dest, opcode, l, r } => {
+                write!(f, "{} = {} {} {}", dest, l, opcode.symbol(), r)
+            }
+            Instruction::Neg { dest, value } => write!(f, "{} = -{}", dest, value),
+            Instruction::Assign { dest, value } => write!(f, "{} = {}", dest, value),
+            Instruction::Print(value) => write!(f, "print {}", value),
+            Instruction::Call {
+                dest,
+                function,
+                args,
+            } => {
+                let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+                write!(f, "{} = call {}({})", dest, function, args.join(", "))
+            }
+            Instruction::Return(value) => write!(f, "return {}", value),
+            Instruction::Label(label) => write!(f, "{}:", label),
+            Instruction::Jump(label) => write!(f, "goto {}", label),
+            Instruction::Branch {
+                relation,
+                l,
+                r,
+                then,
+                otherwise,
+            } => write!(
+                f,
+                "if {} {} {} goto {} else {}",
+                l,
+                relation.symbol(),
+                r,
+                then,
+                otherwise
+            ),
+        }
+    }
+}
+
+/// One instruction per line, e.g. `%0 = a * b`, `c = %0`, `print c`, `if c < 0 goto L0 else L1`.
+/// Functions come first, each headed by `fn f(a, b):` and followed by an empty line.
+impl fmt::Display for Program {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for function in self.functions.iter() {
+            writeln!(f, "fn {}({}):", function.name, function.params.join(", "))?;
+            write!(f, "{}", function.body)?;
+            writeln!(f)?;
+        }
+
+        write!(f, "{}", self.main)
+    }
+}
+
+impl fmt::Display for Body {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.instructions
+            .iter()
+            .try_for_each(|instruction| writeln!(f, "{}", instruction))
+    }
+}
@@ -1,232 +1,934 @@
 use std::cmp;
 use std::collections::HashMap;
+use std::fmt;
+use std::iter;
+
+use crate::ast::{Opcode, Relation, Stmt};
+use crate::classfile;
+use crate::jvm_peephole;
+use crate::ir::{self, Instruction, Label, Operand, Temp};
+
+/// Compiles program to Jasmin assembly of a class with `main` and one static method per function.
+/// Each method is lowered from IR (after common subexpression elimination) by rebuilding expression
+/// trees tagged with their Sethi-Ullman stack limits, then translated and peephole optimised;
+/// `simulate_max_stack` checks the emitted instructions against the computed `.limit stack`.
+/// Program must have passed `semantic::check`, as for `ir::lower`.
+pub fn compile(stmts: &[Box<Stmt>], class_name: &str) -> String {
+    Class::new(&lower(stmts)).jasmin(class_name)
+}
+
+/// Same as `compile`, but produces binary class file directly instead of Jasmin assembly.
+pub fn compile_class(stmts: &[Box<Stmt>], class_name: &str) -> Vec<u8> {
+    Class::new(&lower(stmts)).class_file(class_name)
+}
+
+/// Instruction counts of all methods together before and after peephole optimisation, which both
+/// `compile` and `compile_class` apply.
+pub fn peephole_stats(stmts: &[Box<Stmt>]) -> jvm_peephole::Stats {
+    Class::new(&lower(stmts)).peephole_stats()
+}
+
+/// IR the class is compiled from, after common subexpression elimination
+pub fn lower(stmts: &[Box<Stmt>]) -> ir::Program {
+    ir::eliminate_common_subexpressions(ir::lower(stmts))
+}
+
+/// Methods of generated class, compiled once for any number of outputs
+pub struct Class<'a> {
+    pub main: Method<'a>,
+    pub functions: Vec<Method<'a>>,
+}
+
+impl<'a> Class<'a> {
+    pub fn new(program: &'a ir::Program) -> Class<'a> {
+        let functions = program
+            .functions
+            .iter()
+            .map(|function| compile_method(&function.name, Some(&function.params), &function.body))
+            .collect();
+
+        Class {
+            main: compile_method("main", None, &program.main),
+            functions,
+        }
+    }
+
+    pub fn jasmin(&self, class_name: &str) -> String {
+        generate_code(class_name, &self.main, &self.functions)
+    }
+
+    pub fn class_file(&self, class_name: &str) -> Vec<u8> {
+        classfile::write_class(class_name, &self.main, &self.functions)
+    }
 
-use crate::ast::{Expr, Opcode, Stmt};
+    pub fn peephole_stats(&self) -> jvm_peephole::Stats {
+        iter::once(&self.main)
+            .chain(self.functions.iter())
+            .fold(jvm_peephole::Stats { before: 0, after: 0 }, |stats, method| jvm_peephole::Stats {
+                before: stats.before + method.peephole_stats.before,
+                after: stats.after + method.peephole_stats.after,
+            })
+    }
+}
+
+/// Static method of generated class: `main`, or function taking `arity` ints in slots
+/// `0..arity` and returning int.
+pub struct Method<'a> {
+    pub name: &'a str,
+    pub arity: Option<usize>, // None for main
+    pub instructions: Vec<JVMInstruction<'a>>,
+    pub limit_stack: usize,
+    pub limit_locals: usize,
+    peephole_stats: jvm_peephole::Stats,
+}
 
-// Could've made algorithm work in different way such that only one linear pass is needed instead of two
-// but it is my first time programming in Rust and I wanted to try how could I wrap around predefined enums.
-// In Haskell or anything with GC it's straightforward but in Rust I wanted to create move semantic instead of copying stuff all the time
-// See definitions of enums: TaggedStmt and TaggedExpr for reference and signature of life time specifiers for implementations
-pub fn compile(stmts: &Vec<Box<Stmt>>, class_name: &str) -> String {
-    let mut state = JVMState::new();
+/// Descriptor of function taking `arity` ints and returning int
+pub fn descriptor(arity: usize) -> String {
+    format!("({})I", "I".repeat(arity))
+}
+
+/// Parameters stay in the slots the caller put them in, unless they're reassigned: then they are
+/// copied on entry and handled as any other local from there on.
+fn compile_method<'a>(name: &'a str, params: Option<&'a [String]>, body: &'a ir::Body) -> Method<'a> {
     let mut limit_stack = 0;
 
-    // First linear run to calculate stack limit needed for evaluating expressions.
-    let tagged_stmts = tag_stmts(stmts);
+    let mut tagged_stmts = vec![];
+    for param in params.unwrap_or_default().iter() {
+        let reassigned = body.instructions.iter().any(|instruction| match instruction {
+            Instruction::Assign { dest, .. } => dest == param,
+            _ => false,
+        });
+        if reassigned {
+            let local = Local::Var(param);
+            tagged_stmts.push(TaggedStmt::Store(local, Box::new(TaggedExpr::Load(local))));
+        }
+    }
+
+    // First linear run rebuilding expression trees from IR with stack limits needed to evaluate them.
+    tagged_stmts.extend(tag_body(body));
 
-    // Second linear run translating instructions
-    tagged_stmts.iter().for_each(|tagged_stmt| {
+    let first_slot = params.map_or(1, |params| params.len()); // slot 0 of main keeps String[] args
+    let mut state = JVMState::new(allocate_slots(&tagged_stmts, first_slot), first_slot);
+    for (i, param) in params.unwrap_or_default().iter().enumerate() {
+        state.var_index_map.insert(param.clone(), i);
+    }
+
+    // Second linear run translating instructions; the stack is empty between statements,
+    // so it is empty at every jump and label as well
+    for (i, tagged_stmt) in tagged_stmts.iter().enumerate() {
         limit_stack = cmp::max(limit_stack, tagged_stmt.get_stmt_stack_limit());
 
-        compile_tagged_stmt(&tagged_stmt, &mut state);
-    });
+        compile_tagged_stmt(tagged_stmt, tagged_stmts.get(i + 1), &mut state);
+    }
 
-    state.generate_code(class_name, limit_stack)
+    // Not a debug assertion, so fuzzing release builds checks it as well
+    assert_eq!(
+        simulate_max_stack(&state.instructions),
+        limit_stack,
+        "Sethi-Ullman stack limit does not match emitted instructions"
+    );
+
+    let limit_locals = state.get_limit_locals();
+    let (instructions, peephole_stats) = jvm_peephole::optimize(state.instructions);
+
+    // Rewritten `istore; iload` pair keeps one more value on the stack with `dup`
+    let limit_stack = cmp::max(limit_stack, simulate_max_stack(&instructions));
+
+    Method {
+        name,
+        arity: params.map(|params| params.len()),
+        instructions,
+        limit_stack,
+        limit_locals,
+        peephole_stats,
+    }
 }
 
-struct JVMState {
-    instructions: Vec<String>,
-    var_index_map: HashMap<String, usize>,
+/// Jasmin class with `main` first; labels go in the first column
+fn generate_code(class_name: &str, main: &Method, functions: &[Method]) -> String {
+    let mut code = format!(
+        "{}{}{}",
+        format_args!(".class public {}\n", class_name),
+        ".super  java/lang/Object\n",
+        ".method public <init>()V\n\taload_0\n\tinvokespecial java/lang/Object/<init>()V\n\treturn\n.end method\n",
+    );
+
+    for method in iter::once(main).chain(functions.iter()) {
+        let method_descriptor = match method.arity {
+            Some(arity) => descriptor(arity),
+            None => "([Ljava/lang/String;)V".to_string(),
+        };
+
+        code += &format!(".method public static {}{}\n", method.name, method_descriptor);
+        code += &format!(".limit stack {}\n", method.limit_stack);
+        code += &format!(".limit locals {}\n", method.limit_locals);
+
+        for instruction in method.instructions.iter() {
+            code += &match instruction {
+                JVMInstruction::Label(_) => format!("{}\n", instruction),
+                JVMInstruction::InvokeStatic(name, arity) => {
+                    format!("\tinvokestatic {}/{}{}\n", class_name, name, descriptor(*arity))
+                }
+                _ => format!("\t{}\n", instruction),
+            };
+        }
+
+        if method.arity.is_none() {
+            code += "\treturn\n";
+        }
+        code += ".end method\n";
+    }
+
+    code
 }
 
-impl JVMState {
-    fn new() -> JVMState {
-        JVMState {
-            instructions: vec![],
-            var_index_map: HashMap::new(),
+/// Instructions of generated methods. `GetStatic` and `InvokeVirtual` only ever refer to
+/// `System.out` and `PrintStream.println(I)V`, the sole library field and method programs use;
+/// `InvokeStatic` calls function of given name and arity in the generated class.
+/// `Label` marks jump target and takes no space in the method code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JVMInstruction<'a> {
+    IConst(i32), // -1..=5
+    BiPush(i8),
+    SiPush(i16),
+    Ldc(i32),
+    ILoad(usize),
+    IStore(usize),
+    IAdd,
+    ISub,
+    IMul,
+    IDiv,
+    IRem,
+    IAnd,
+    IOr,
+    IXor,
+    IShl,
+    IShr,
+    INeg,
+    IInc(usize, i8),
+    Dup,
+    Swap,
+    GetStatic,
+    InvokeVirtual,
+    InvokeStatic(&'a str, usize),
+    IReturn,
+    Label(Label),
+    Goto(Label),
+    IfICmp(Relation, Label), // pops two values, jumps when `relation` holds between them
+}
+
+impl JVMInstruction<'_> {
+    /// Shortest instruction pushing constant
+    pub fn constant<'a>(n: i32) -> JVMInstruction<'a> {
+        use JVMInstruction::*;
+
+        match n {
+            -1..=5 => IConst(n),
+            -128..=127 => BiPush(n as i8),
+            -32768..=32767 => SiPush(n as i16),
+            _ => Ldc(n),
+        }
+    }
+
+    pub fn arithmetic<'a>(opcode: Opcode) -> JVMInstruction<'a> {
+        match opcode {
+            Opcode::Add => JVMInstruction::IAdd,
+            Opcode::Sub => JVMInstruction::ISub,
+            Opcode::Mul => JVMInstruction::IMul,
+            Opcode::Div => JVMInstruction::IDiv,
+            Opcode::Rem => JVMInstruction::IRem,
+            Opcode::And => JVMInstruction::IAnd,
+            Opcode::Or => JVMInstruction::IOr,
+            Opcode::Xor => JVMInstruction::IXor,
+            Opcode::Shl => JVMInstruction::IShl,
+            Opcode::Shr => JVMInstruction::IShr,
         }
     }
 
-    fn generate_code(&self, class_name: &str, limit_stack: usize) -> String {
-        let instructions = self.instructions.join("\n\t");
-        let limit_locals = self.var_index_map.len() + 1;
+    /// Change of operand stack depth after executing instruction
+    pub fn stack_effect(self) -> isize {
+        use JVMInstruction::*;
 
-        format!(
-            "{}{}{}{}{}{}{}{}",
-            format!(".class public {}\n", class_name),
-            String::from(".super  java/lang/Object\n"),
-            String::from(".method public <init>()V\n\taload_0\n\tinvokespecial java/lang/Object/<init>()V\n\treturn\n.end method\n"),
-            String::from(".method public static main([Ljava/lang/String;)V\n"),
-            format!(".limit stack {}\n", limit_stack),
-            format!(".limit locals {}\n\t", limit_locals),
-            instructions,
-            String::from("\n\treturn\n.end method\n")
-        )
+        match self {
+            IConst(_) | BiPush(_) | SiPush(_) | Ldc(_) | ILoad(_) | Dup | GetStatic => 1,
+            IStore(_) | IAdd | ISub | IMul | IDiv | IRem | IAnd | IOr | IXor | IShl | IShr => -1,
+            IReturn => -1,
+            InvokeStatic(_, arity) => 1 - arity as isize,
+            INeg | IInc(_, _) | Swap | Label(_) | Goto(_) => 0,
+            InvokeVirtual | IfICmp(_, _) => -2, // PrintStream and printed value, compared values
+        }
     }
 
-    fn push_constant(&mut self, n: i32) {
-        let push_instruction = match n {
-            -1 => String::from("iconst_m1"),
-            1..=5 => format!("iconst_{}", n),
-            -128..=-2 | 6..=127 => format!("bipush {}", n),
-            -32768..=-129 | 128..=32767 => format!("sipush {}", n),
-            _ => format!("ldc {}", n),
-        };
+    /// Where control may go after executing instruction, other than the next one
+    pub fn jump_target(self) -> Option<Label> {
+        match self {
+            JVMInstruction::Goto(label) | JVMInstruction::IfICmp(_, label) => Some(label),
+            _ => None,
+        }
+    }
+}
+
+/// Suffix of `if_icmp<cond>`
+fn condition(relation: Relation) -> &'static str {
+    match relation {
+        Relation::Lt => "lt",
+        Relation::Le => "le",
+        Relation::Gt => "gt",
+        Relation::Ge => "ge",
+        Relation::Eq => "eq",
+        Relation::Ne => "ne",
+    }
+}
+
+/// Jasmin syntax, except that `invokestatic` leaves out the class name (see `generate_code`)
+impl fmt::Display for JVMInstruction<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use JVMInstruction::*;
+
+        match self {
+            IConst(-1) => write!(f, "iconst_m1"),
+            IConst(n) => write!(f, "iconst_{}", n),
+            BiPush(n) => write!(f, "bipush {}", n),
+            SiPush(n) => write!(f, "sipush {}", n),
+            Ldc(n) => write!(f, "ldc {}", n),
+            ILoad(i @ 0..=3) => write!(f, "iload_{}", i),
+            ILoad(i) => write!(f, "iload {}", i),
+            IStore(i @ 0..=3) => write!(f, "istore_{}", i),
+            IStore(i) => write!(f, "istore {}", i),
+            IAdd => write!(f, "iadd"),
+            ISub => write!(f, "isub"),
+            IMul => write!(f, "imul"),
+            IDiv => write!(f, "idiv"),
+            IRem => write!(f, "irem"),
+            IAnd => write!(f, "iand"),
+            IOr => write!(f, "ior"),
+            IXor => write!(f, "ixor"),
+            IShl => write!(f, "ishl"),
+            IShr => write!(f, "ishr"),
+            INeg => write!(f, "ineg"),
+            IInc(i, n) => write!(f, "iinc {} {}", i, n),
+            Dup => write!(f, "dup"),
+            Swap => write!(f, "swap"),
+            GetStatic => write!(f, "getstatic  java/lang/System/out Ljava/io/PrintStream;"),
+            InvokeVirtual => write!(f, "invokevirtual java/io/PrintStream/println(I)V"),
+            InvokeStatic(name, arity) => write!(f, "invokestatic {}{}", name, descriptor(*arity)),
+            IReturn => write!(f, "ireturn"),
+            Label(label) => write!(f, "{}:", label),
+            Goto(label) => write!(f, "goto {}", label),
+            IfICmp(relation, label) => write!(f, "if_icmp{} {}", condition(*relation), label),
+        }
+    }
+}
+
+struct JVMState<'a> {
+    instructions: Vec<JVMInstruction<'a>>,
+    first_slot: usize, // slots below hold arguments
+    store_slots: Vec<usize>, // slot for each store, in order of emission; see allocate_slots
+    stores_emitted: usize,
+    var_index_map: HashMap<String, usize>, // slot holding current value of local
+    label_index_maps: HashMap<Label, HashMap<String, usize>>, // var_index_map at jumps to label
+}
 
-        self.instructions.push(push_instruction)
+impl<'a> JVMState<'a> {
+    fn new(store_slots: Vec<usize>, first_slot: usize) -> JVMState<'a> {
+        JVMState {
+            instructions: vec![],
+            first_slot,
+            store_slots,
+            stores_emitted: 0,
+            var_index_map: HashMap::new(),
+            label_index_maps: HashMap::new(),
+        }
+    }
+
+    fn push_constant(&mut self, n: i32) {
+        self.instructions.push(JVMInstruction::constant(n))
     }
 
     fn push_get_static_all_print(&mut self) {
-        self.instructions.push(String::from(
-            "getstatic  java/lang/System/out Ljava/io/PrintStream;",
-        ))
+        self.instructions.push(JVMInstruction::GetStatic)
     }
 
     fn push_call_print(&mut self) {
-        self.instructions.push(String::from(
-            "invokevirtual java/io/PrintStream/println(I)V",
-        ))
+        self.instructions.push(JVMInstruction::InvokeVirtual)
     }
 
     fn push_opcode(&mut self, opcode: &Opcode) {
-        use Opcode::*;
+        self.instructions.push(JVMInstruction::arithmetic(*opcode));
+    }
 
-        let opcode_instruction = match *opcode {
-            Add => String::from("iadd"),
-            Sub => String::from("isub"),
-            Mul => String::from("imul"),
-            Div => String::from("idiv"),
-        };
+    fn push_load(&mut self, local: &Local) {
+        let i = self.var_index_map[&local.to_string()];
 
-        self.instructions.push(opcode_instruction);
+        self.instructions.push(JVMInstruction::ILoad(i));
     }
 
-    fn push_load(&mut self, ident: &String) {
-        let i = self
-            .var_index_map
-            .get(ident)
-            .expect("Use of undeclared variable");
+    fn push_store(&mut self, local: &Local) {
+        let i = self.store_slots[self.stores_emitted];
+        self.stores_emitted += 1;
 
-        let load_instruction = match i {
-            0..=3 => format!("iload_{}", i),
-            _ => format!("iload {}", i),
-        };
+        self.var_index_map.insert(local.to_string(), i);
+        self.instructions.push(JVMInstruction::IStore(i));
+    }
 
-        self.instructions.push(load_instruction);
+    fn push_swap(&mut self) {
+        self.instructions.push(JVMInstruction::Swap)
     }
 
-    fn push_store(&mut self, ident: &String) {
-        let new_free_slot = self.get_next_free_var_slot();
+    /// Locals hold the same slots on every jump to label (see allocate_slots), so any of them will do
+    fn push_label(&mut self, label: Label) {
+        if let Some(var_index_map) = self.label_index_maps.remove(&label) {
+            self.var_index_map = var_index_map;
+        }
 
-        let i = self
-            .var_index_map
-            .entry(ident.clone())
-            .or_insert(new_free_slot);
+        self.instructions.push(JVMInstruction::Label(label));
+    }
 
-        let push_instruction = match i {
-            0..=3 => format!("istore_{}", i),
-            _ => format!("istore {}", i),
-        };
+    /// Records slots of locals at a jump to label, whether or not the jump itself is emitted
+    fn jump_to(&mut self, label: Label) {
+        let var_index_map = &self.var_index_map;
 
-        self.instructions.push(push_instruction);
+        self.label_index_maps
+            .entry(label)
+            .or_insert_with(|| var_index_map.clone());
     }
 
-    fn push_swap(&mut self) {
-        self.instructions.push(String::from("swap"))
+    fn get_limit_locals(&self) -> usize {
+        self.store_slots.iter().max().map_or(self.first_slot, |slot| slot + 1)
     }
+}
 
-    fn get_next_free_var_slot(&self) -> usize {
-        self.var_index_map.len() + 1
+/// Local variable slot holder: variable from source or IR temporary used more than once.
+/// Temporaries print as `%N`, so both share `var_index_map` and live ranges without clashing.
+#[derive(Clone, Copy)]
+enum Local<'a> {
+    Var(&'a str),
+    Temp(Temp),
+}
+
+impl fmt::Display for Local<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Local::Var(ident) => write!(f, "{}", ident),
+            Local::Temp(temp) => write!(f, "{}", temp),
+        }
     }
 }
 
-/// Wrapper for ast::Stmt for keeping TaggedExpr
+/// Statement rebuilt from IR instructions, keeping TaggedExpr
 enum TaggedStmt<'a> {
-    SAss(&'a String, Box<TaggedExpr<'a>>),
-    SExpr(Box<TaggedExpr<'a>>),
+    Store(Local<'a>, Box<TaggedExpr<'a>>),
+    Print(Box<TaggedExpr<'a>>),
+    Label(Label),
+    Goto(Label),
+    Branch(Relation, Box<TaggedExpr<'a>>, Box<TaggedExpr<'a>>, Label, Label), // then, otherwise
+    Return(Box<TaggedExpr<'a>>),
 }
 
-impl<'a> TaggedStmt<'_> {
+impl<'a> TaggedStmt<'a> {
     fn get_stmt_stack_limit(&self) -> usize {
         use TaggedStmt::*;
 
         match self {
-            SAss(_, tagged_expr) => tagged_expr.get_expr_stack_limit() + 1, // additional istore
-            SExpr(tagged_expr) => tagged_expr.get_expr_stack_limit() + 2, // additional getstatic and invokevirtual for print
+            // istore and ireturn only pop the value
+            Store(_, tagged_expr) | Return(tagged_expr) => tagged_expr.get_expr_stack_limit(),
+            // getstatic is pushed before a single value expression or after a bigger one, followed by swap
+            Print(tagged_expr) => cmp::max(tagged_expr.get_expr_stack_limit(), 2),
+            Label(_) | Goto(_) => 0,
+            // Both operands are on the stack for if_icmp, as for an operation
+            Branch(_, l_expr, r_expr, _, _) => pair_stack_limit(l_expr, r_expr),
+        }
+    }
+
+    fn for_each_load<F: FnMut(&Local<'a>)>(&self, f: &mut F) {
+        use TaggedStmt::*;
+
+        match self {
+            Store(_, tagged_expr) | Print(tagged_expr) | Return(tagged_expr) => {
+                for_each_load(tagged_expr, f)
+            }
+            Branch(_, l_expr, r_expr, _, _) => {
+                for_each_load(l_expr, f);
+                for_each_load(r_expr, f);
+            }
+            Label(_) | Goto(_) => {}
         }
     }
 }
-/// Wrapper for ast::Expr keeping stack_limit needed to compile expression
+/// Expression tree keeping stack_limit needed to compile expression
 enum TaggedExpr<'a> {
     Number(i32),
-    Ident(&'a String),
+    Load(Local<'a>),
     Op(Box<TaggedExpr<'a>>, &'a Opcode, Box<TaggedExpr<'a>>, usize), // usize keeps stack_limit
+    Neg(Box<TaggedExpr<'a>>), // negated in place, needs as much stack as its operand
+    Call(&'a str, Vec<TaggedExpr<'a>>, usize), // usize keeps stack_limit
 }
 
-impl<'a> TaggedExpr<'_> {
+impl TaggedExpr<'_> {
     fn get_expr_stack_limit(&self) -> usize {
         use TaggedExpr::*;
 
         match *self {
-            Number(_) | Ident(_) => 1,
-            Op(_, _, _, stack_size) => stack_size,
+            Number(_) | Load(_) => 1,
+            Op(_, _, _, stack_size) | Call(_, _, stack_size) => stack_size,
+            Neg(ref tagged_expr) => tagged_expr.get_expr_stack_limit(),
+        }
+    }
+
+    fn contains_call(&self) -> bool {
+        use TaggedExpr::*;
+
+        match self {
+            Number(_) | Load(_) => false,
+            Op(l_expr, _, r_expr, _) => l_expr.contains_call() || r_expr.contains_call(),
+            Neg(tagged_expr) => tagged_expr.contains_call(),
+            Call(_, _, _) => true,
+        }
+    }
+
+    /// Same as `opt::may_fail`; temporaries loaded from locals were computed before
+    fn may_fail(&self) -> bool {
+        use TaggedExpr::*;
+
+        match self {
+            Number(_) | Load(_) => false,
+            Op(l_expr, opcode, r_expr, _) => {
+                let divisor_may_be_zero =
+                    opcode.is_division() && !matches!(**r_expr, Number(n) if n != 0);
+
+                divisor_may_be_zero || l_expr.may_fail() || r_expr.may_fail()
+            }
+            Neg(tagged_expr) => tagged_expr.may_fail(),
+            Call(_, _, _) => true,
         }
     }
 }
 
-fn tag_stmts(stmts: &Vec<Box<Stmt>>) -> Vec<TaggedStmt> {
-    stmts.iter().map(|stmt| tag_stmt(stmt)).collect()
+/// Stack machine needs expression trees back: temporary used once is inlined into its user, which
+/// lowering places in the same statement, with no assignment in between. Temporary used more than once
+/// (after common subexpression elimination) is stored in its own local and loaded at every use.
+fn tag_body(body: &ir::Body) -> Vec<TaggedStmt<'_>> {
+    let mut uses: HashMap<Temp, usize> = HashMap::new();
+    for instruction in body.instructions.iter() {
+        for operand in instruction.operands() {
+            if let Operand::Temp(temp) = operand {
+                *uses.entry(*temp).or_insert(0) += 1;
+            }
+        }
+    }
+
+    let mut pending: HashMap<Temp, TaggedExpr> = HashMap::new();
+    let mut tagged_stmts = vec![];
+
+    for instruction in body.instructions.iter() {
+        match instruction {
+            Instruction::BinOp { dest, opcode, l, r } => {
+                let tagged_l_expr = tag_operand(l, &mut pending);
+                let tagged_r_expr = tag_operand(r, &mut pending);
+                let tagged_expr = tag_op(tagged_l_expr, opcode, tagged_r_expr);
+
+                define_temp(*dest, tagged_expr, &uses, &mut pending, &mut tagged_stmts);
+            }
+            Instruction::Neg { dest, value } => {
+                let tagged_expr = TaggedExpr::Neg(Box::new(tag_operand(value, &mut pending)));
+
+                define_temp(*dest, tagged_expr, &uses, &mut pending, &mut tagged_stmts);
+            }
+            Instruction::Assign { dest, value } => tagged_stmts.push(TaggedStmt::Store(
+                Local::Var(dest),
+                Box::new(tag_operand(value, &mut pending)),
+            )),
+            Instruction::Print(value) => tagged_stmts.push(TaggedStmt::Print(Box::new(
+                tag_operand(value, &mut pending),
+            ))),
+            Instruction::Call {
+                dest,
+                function,
+                args,
+            } => {
+                let tagged_args: Vec<TaggedExpr> =
+                    args.iter().map(|arg| tag_operand(arg, &mut pending)).collect();
+                // i-th argument is evaluated with the i before it on the stack
+                let stack_limit = tagged_args
+                    .iter()
+                    .enumerate()
+                    .map(|(i, tagged_arg)| i + tagged_arg.get_expr_stack_limit())
+                    .fold(1, cmp::max);
+                let tagged_expr = TaggedExpr::Call(function, tagged_args, stack_limit);
+
+                define_temp(*dest, tagged_expr, &uses, &mut pending, &mut tagged_stmts);
+            }
+            Instruction::Return(value) => tagged_stmts.push(TaggedStmt::Return(Box::new(
+                tag_operand(value, &mut pending),
+            ))),
+            Instruction::Label(label) => tagged_stmts.push(TaggedStmt::Label(*label)),
+            Instruction::Jump(label) => tagged_stmts.push(TaggedStmt::Goto(*label)),
+            Instruction::Branch {
+                relation,
+                l,
+                r,
+                then,
+                otherwise,
+            } => {
+                let tagged_l_expr = tag_operand(l, &mut pending);
+                let tagged_r_expr = tag_operand(r, &mut pending);
+
+                tagged_stmts.push(TaggedStmt::Branch(
+                    *relation,
+                    Box::new(tagged_l_expr),
+                    Box::new(tagged_r_expr),
+                    *then,
+                    *otherwise,
+                ));
+            }
+        }
+    }
+
+    tagged_stmts
 }
 
-fn tag_stmt(stmt: &Stmt) -> TaggedStmt {
-    match stmt {
-        Stmt::SAss(ident, expr) => TaggedStmt::SAss(ident, Box::new(tag_expr(expr))),
-        Stmt::SExpr(expr) => TaggedStmt::SExpr(Box::new(tag_expr(expr))),
+fn define_temp<'a>(
+    temp: Temp,
+    tagged_expr: TaggedExpr<'a>,
+    uses: &HashMap<Temp, usize>,
+    pending: &mut HashMap<Temp, TaggedExpr<'a>>,
+    tagged_stmts: &mut Vec<TaggedStmt<'a>>,
+) {
+    if uses.get(&temp).copied().unwrap_or(0) > 1 {
+        tagged_stmts.push(TaggedStmt::Store(Local::Temp(temp), Box::new(tagged_expr)));
+    } else {
+        pending.insert(temp, tagged_expr);
     }
 }
 
-fn tag_expr(expr: &Expr) -> TaggedExpr {
-    match expr {
-        Expr::Number(n) => TaggedExpr::Number(*n),
-        Expr::Ident(id) => TaggedExpr::Ident(id),
-        Expr::Op(l_expr, opcode, r_expr) => {
-            let tagged_l_expr = tag_expr(&l_expr);
-            let tagged_r_expr = tag_expr(&r_expr);
+fn tag_operand<'a>(
+    operand: &'a Operand,
+    pending: &mut HashMap<Temp, TaggedExpr<'a>>,
+) -> TaggedExpr<'a> {
+    match operand {
+        Operand::Const(n) => TaggedExpr::Number(*n),
+        Operand::Var(ident) => TaggedExpr::Load(Local::Var(ident)),
+        Operand::Temp(temp) => pending
+            .remove(temp)
+            .unwrap_or(TaggedExpr::Load(Local::Temp(*temp))),
+    }
+}
 
-            let stack_limit = cmp::min(
-                tagged_l_expr.get_expr_stack_limit(),
-                tagged_r_expr.get_expr_stack_limit(),
-            ) + 1;
+fn tag_op<'a>(
+    tagged_l_expr: TaggedExpr<'a>,
+    opcode: &'a Opcode,
+    tagged_r_expr: TaggedExpr<'a>,
+) -> TaggedExpr<'a> {
+    let stack_limit = pair_stack_limit(&tagged_l_expr, &tagged_r_expr);
+
+    TaggedExpr::Op(
+        Box::new(tagged_l_expr),
+        opcode,
+        Box::new(tagged_r_expr),
+        stack_limit,
+    )
+}
 
-            TaggedExpr::Op(
-                Box::new(tagged_l_expr),
-                opcode,
-                Box::new(tagged_r_expr),
-                stack_limit,
-            )
+/// Sethi-Ullman numbering: the deeper subtree is evaluated first, so its result
+/// only sits on top of the stack while the other one is computed.
+fn pair_stack_limit(l_expr: &TaggedExpr, r_expr: &TaggedExpr) -> usize {
+    let (l_limit, r_limit) = (l_expr.get_expr_stack_limit(), r_expr.get_expr_stack_limit());
+
+    if in_order(l_expr, r_expr) {
+        cmp::max(l_limit, r_limit + 1)
+    } else if l_limit == r_limit {
+        l_limit + 1
+    } else {
+        cmp::max(l_limit, r_limit)
+    }
+}
+
+/// Calls may print, so operands are evaluated left to right whatever their depth when one of them
+/// makes a call and the other one could fail or print too. A call can move past an operand which
+/// can't fail, as calls can't change caller's locals.
+fn in_order(l_expr: &TaggedExpr, r_expr: &TaggedExpr) -> bool {
+    (l_expr.contains_call() && r_expr.may_fail()) || (r_expr.contains_call() && l_expr.may_fail())
+}
+
+/// Value written by one store, live until its last load. Positions count two per statement:
+/// loads of statement i happen at 2i, its store at 2i + 1, so a value last read by a statement
+/// can share slot with the value that statement stores.
+struct LiveRange {
+    start: usize,
+    end: usize,
+    accesses: usize,
+    merged_into: Option<usize>, // range sharing slot with this one, see merge_ranges
+}
+
+/// Live-range analysis and slot allocation. Every store starts a new range, so a reassigned variable
+/// may move between slots. Ranges are coloured by linear scan in order of their start, which for
+/// interval graphs uses as few slots as possible; the colours with the most loads and stores then get
+/// the lowest slots, so the hottest values can use the `iload_n`/`istore_n` forms of slots 1-3.
+/// Returns slot for each store in the order `compile_tagged_stmt` emits them; slots below
+/// `first_slot` hold arguments. Parameters which are never reassigned stay in their argument slots
+/// and have no ranges.
+///
+/// Where jumps meet at a label, the ranges holding a variable on each of them are merged into one
+/// spanning all of them, so that the variable is in the same slot whichever way the label is reached.
+/// Only loops jump backward, from the end of their body to the head: ranges holding a variable at
+/// the head are merged with the ones holding it at the jump and kept until it, as the next iteration
+/// may read any of them.
+fn allocate_slots(tagged_stmts: &[TaggedStmt], first_slot: usize) -> Vec<usize> {
+    let mut ranges: Vec<LiveRange> = vec![];
+    let mut current: HashMap<String, usize> = HashMap::new();
+    let mut at_jumps: HashMap<Label, Vec<HashMap<String, usize>>> = HashMap::new();
+    let mut at_labels: HashMap<Label, HashMap<String, usize>> = HashMap::new();
+
+    for (i, tagged_stmt) in tagged_stmts.iter().enumerate() {
+        tagged_stmt.for_each_load(&mut |local| {
+            if let Some(range) = current.get(&local.to_string()) {
+                let root = find_range(&ranges, *range);
+                let range = &mut ranges[root];
+                range.end = 2 * i;
+                range.accesses += 1;
+            }
+        });
+
+        match tagged_stmt {
+            TaggedStmt::Store(local, _) => {
+                current.insert(local.to_string(), ranges.len());
+                ranges.push(LiveRange {
+                    start: 2 * i + 1,
+                    end: 2 * i + 1,
+                    accesses: 1,
+                    merged_into: None,
+                });
+            }
+            TaggedStmt::Print(_) | TaggedStmt::Return(_) => {}
+            TaggedStmt::Branch(_, _, _, then, otherwise) => {
+                for label in [*then, *otherwise].iter() {
+                    match at_labels.get(label) {
+                        Some(at_label) => loop_back(&mut ranges, at_label, &current, 2 * i),
+                        None => at_jumps.entry(*label).or_default().push(current.clone()),
+                    }
+                }
+            }
+            TaggedStmt::Goto(label) => match at_labels.get(label) {
+                Some(at_label) => loop_back(&mut ranges, at_label, &current, 2 * i),
+                None => at_jumps.entry(*label).or_default().push(current.clone()),
+            },
+            TaggedStmt::Label(label) => {
+                let incoming = at_jumps.remove(label).unwrap_or_default();
+                current = merge_ranges(&mut ranges, &incoming);
+                at_labels.insert(*label, current.clone());
+            }
         }
     }
+
+    let mut colours: Vec<usize> = vec![0; ranges.len()];
+    let mut colour_ends: Vec<usize> = vec![];
+    let mut colour_accesses: Vec<usize> = vec![];
+
+    // Merged range keeps the lowest index of its parts, so roots are still visited in order of start
+    for (i, range) in ranges.iter().enumerate() {
+        if range.merged_into.is_some() {
+            continue;
+        }
+
+        let colour = match colour_ends.iter().position(|end| *end < range.start) {
+            Some(colour) => colour,
+            None => {
+                colour_ends.push(0);
+                colour_accesses.push(0);
+                colour_ends.len() - 1
+            }
+        };
+
+        colour_ends[colour] = range.end;
+        colour_accesses[colour] += range.accesses;
+        colours[i] = colour;
+    }
+
+    let mut by_heat: Vec<usize> = (0..colour_ends.len()).collect();
+    by_heat.sort_by_key(|colour| cmp::Reverse(colour_accesses[*colour]));
+
+    let mut colour_slots = vec![0; by_heat.len()];
+    for (rank, colour) in by_heat.iter().enumerate() {
+        colour_slots[*colour] = first_slot + rank;
+    }
+
+    (0..ranges.len())
+        .map(|i| colour_slots[colours[find_range(&ranges, i)]])
+        .collect()
+}
+
+fn find_range(ranges: &[LiveRange], mut i: usize) -> usize {
+    while let Some(parent) = ranges[i].merged_into {
+        i = parent;
+    }
+
+    i
 }
 
-fn compile_tagged_stmt(stmt: &TaggedStmt, state: &mut JVMState) {
+/// Locals reaching label on every jump to it, each with its ranges merged into one.
+/// Locals missing on some jump can't be read after the label, as `ir::lower` checks.
+fn merge_ranges(
+    ranges: &mut [LiveRange],
+    incoming: &[HashMap<String, usize>],
+) -> HashMap<String, usize> {
+    let mut merged = HashMap::new();
+
+    let first = match incoming.first() {
+        Some(first) => first,
+        None => return merged,
+    };
+
+    for (local, range) in first.iter() {
+        let parts: Option<Vec<usize>> = incoming
+            .iter()
+            .map(|current| current.get(local).map(|part| find_range(ranges, *part)))
+            .collect();
+
+        let mut parts = match parts {
+            Some(parts) => parts,
+            None => continue,
+        };
+        parts.sort_unstable();
+        parts.dedup();
+
+        for part in parts[1..].iter() {
+            union_ranges(ranges, parts[0], *part);
+        }
+
+        merged.insert(local.clone(), find_range(ranges, *range));
+    }
+
+    merged
+}
+
+/// Backward jump at `end` to a label where locals were in `at_label` ranges
+fn loop_back(
+    ranges: &mut [LiveRange],
+    at_label: &HashMap<String, usize>,
+    current: &HashMap<String, usize>,
+    end: usize,
+) {
+    for (local, range) in at_label.iter() {
+        let mut root = find_range(ranges, *range);
+
+        if let Some(part) = current.get(local) {
+            let part = find_range(ranges, *part);
+            if part != root {
+                root = cmp::min(root, part);
+                union_ranges(ranges, root, cmp::max(root, part));
+            }
+        }
+
+        ranges[root].end = cmp::max(ranges[root].end, end);
+    }
+}
+
+/// Merges root range `part` into root range `root`, which has lower index
+fn union_ranges(ranges: &mut [LiveRange], root: usize, part: usize) {
+    ranges[part].merged_into = Some(root);
+
+    let (start, end, accesses) = (ranges[part].start, ranges[part].end, ranges[part].accesses);
+    let root_range = &mut ranges[root];
+    root_range.start = cmp::min(root_range.start, start);
+    root_range.end = cmp::max(root_range.end, end);
+    root_range.accesses += accesses;
+}
+
+fn for_each_load<'a, F: FnMut(&Local<'a>)>(tagged_expr: &TaggedExpr<'a>, f: &mut F) {
+    match tagged_expr {
+        TaggedExpr::Number(_) => {}
+        TaggedExpr::Load(local) => f(local),
+        TaggedExpr::Op(l_expr, _, r_expr, _) => {
+            for_each_load(l_expr, f);
+            for_each_load(r_expr, f);
+        }
+        TaggedExpr::Neg(tagged_expr) => for_each_load(tagged_expr, f),
+        TaggedExpr::Call(_, tagged_args, _) => {
+            tagged_args.iter().for_each(|tagged_arg| for_each_load(tagged_arg, f))
+        }
+    }
+}
+
+/// `next` statement lets jumps to the label right after them fall through instead
+fn compile_tagged_stmt<'a>(
+    stmt: &TaggedStmt<'a>,
+    next: Option<&TaggedStmt<'a>>,
+    state: &mut JVMState<'a>,
+) {
     use TaggedStmt::*;
+
+    let next_label = match next {
+        Some(Label(label)) => Some(*label),
+        _ => None,
+    };
+
     match stmt {
-        SAss(ident, expr) => {
-            compile_tagged_expr(&expr, state);
-            state.push_store(&ident);
+        Store(local, expr) => {
+            compile_tagged_expr(expr, state);
+            state.push_store(local);
         }
-        SExpr(expr) => {
+        Print(expr) => {
             if expr.get_expr_stack_limit() == 1 {
                 state.push_get_static_all_print();
-                compile_tagged_expr(&expr, state);
+                compile_tagged_expr(expr, state);
             } else {
-                compile_tagged_expr(&expr, state);
+                compile_tagged_expr(expr, state);
                 state.push_get_static_all_print();
                 state.push_swap();
             }
 
             state.push_call_print();
         }
+        Label(label) => state.push_label(*label),
+        Goto(label) => {
+            state.jump_to(*label);
+
+            if next_label != Some(*label) {
+                state.instructions.push(JVMInstruction::Goto(*label));
+            }
+        }
+        Branch(relation, l_expr, r_expr, then, otherwise) => {
+            // Deeper operand goes first, as for operations; swapping operands flips the relation
+            let relation = if in_order(l_expr, r_expr)
+                || l_expr.get_expr_stack_limit() >= r_expr.get_expr_stack_limit()
+            {
+                compile_tagged_expr(l_expr, state);
+                compile_tagged_expr(r_expr, state);
+                *relation
+            } else {
+                compile_tagged_expr(r_expr, state);
+                compile_tagged_expr(l_expr, state);
+                relation.flip()
+            };
+
+            state.jump_to(*then);
+            state.jump_to(*otherwise);
+
+            if next_label == Some(*then) {
+                state.instructions.push(JVMInstruction::IfICmp(relation.negate(), *otherwise));
+            } else {
+                state.instructions.push(JVMInstruction::IfICmp(relation, *then));
+
+                if next_label != Some(*otherwise) {
+                    state.instructions.push(JVMInstruction::Goto(*otherwise));
+                }
+            }
+        }
+        Return(expr) => {
+            compile_tagged_expr(expr, state);
+            state.instructions.push(JVMInstruction::IReturn);
+        }
     }
 }
 
-fn compile_tagged_expr(expr: &TaggedExpr, state: &mut JVMState) {
+fn compile_tagged_expr<'a>(expr: &TaggedExpr<'a>, state: &mut JVMState<'a>) {
     use TaggedExpr::*;
     match expr {
         Number(n) => state.push_constant(*n),
-        Ident(ident) => state.push_load(ident),
+        Load(local) => state.push_load(local),
         Op(l_expr, opcode, r_expr, _) => {
-            let swap_occured = l_expr.get_expr_stack_limit() <= r_expr.get_expr_stack_limit();
+            let swap_occured = !in_order(l_expr, r_expr)
+                && l_expr.get_expr_stack_limit() <= r_expr.get_expr_stack_limit();
 
             let first_expr = if !swap_occured { l_expr } else { r_expr };
             let second_expr = if !swap_occured { r_expr } else { l_expr };
@@ -234,11 +936,67 @@ fn compile_tagged_expr(expr: &TaggedExpr, state: &mut JVMState) {
             compile_tagged_expr(first_expr, state);
             compile_tagged_expr(second_expr, state);
 
-            if swap_occured && [Opcode::Sub, Opcode::Div].contains(*opcode) {
+            if swap_occured && !opcode.is_commutative() {
                 state.push_swap();
             }
 
             state.push_opcode(opcode);
         }
+        Neg(tagged_expr) => {
+            compile_tagged_expr(tagged_expr, state);
+            state.instructions.push(JVMInstruction::INeg);
+        }
+        Call(name, tagged_args, _) => {
+            for tagged_arg in tagged_args.iter() {
+                compile_tagged_expr(tagged_arg, state);
+            }
+            state.instructions.push(JVMInstruction::InvokeStatic(name, tagged_args.len()));
+        }
     }
 }
+
+/// Independent check of the stack limit: simulates emitted instructions and returns the real maximum stack depth.
+/// Depth is followed along jumps as well; it has to be the same however a label is reached,
+/// as the verifier requires. Code after `goto` or `ireturn` continues with the depth recorded at its
+/// label; depth at a label is recorded for jumps back to it from later in the code.
+fn simulate_max_stack(instructions: &[JVMInstruction]) -> usize {
+    let mut label_depths: HashMap<Label, isize> = HashMap::new();
+    let mut depth: Option<isize> = Some(0); // None after goto and ireturn, until the next label
+    let mut max_depth: isize = 0;
+
+    for instruction in instructions.iter() {
+        if let JVMInstruction::Label(label) = instruction {
+            let recorded = label_depths.get(label).copied();
+
+            depth = match (depth, recorded) {
+                (Some(depth), Some(recorded)) => {
+                    assert_eq!(depth, recorded, "Stack depth differs between jumps to {}", label);
+                    Some(depth)
+                }
+                (depth, recorded) => depth.or(recorded),
+            };
+            if let Some(depth) = depth {
+                label_depths.insert(*label, depth);
+            }
+            continue;
+        }
+
+        let mut current = depth.expect("Unreachable instruction");
+        current += instruction.stack_effect();
+
+        assert!(current >= 0, "Stack underflow at: {}", instruction);
+        max_depth = cmp::max(max_depth, current);
+
+        if let Some(label) = instruction.jump_target() {
+            let recorded = *label_depths.entry(label).or_insert(current);
+            assert_eq!(current, recorded, "Stack depth differs between jumps to {}", label);
+        }
+
+        depth = match instruction {
+            JVMInstruction::Goto(_) | JVMInstruction::IReturn => None,
+            _ => Some(current),
+        };
+    }
+
+    max_depth as usize
+}
@@ -0,0 +1,134 @@
+use crate::jvm::JVMInstruction::{self, *};
+
+/// Instruction counts of method before and after peephole optimisation; labels don't count
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub before: usize,
+    pub after: usize,
+}
+
+/// Rewrites instruction windows until none applies:
+/// - `iload n; push k; iadd; istore n` (and `push k; iload n; ...`, `isub`) becomes `iinc n k`
+/// - `istore n; iload n` becomes `dup; istore n`, or disappears when slot n isn't read afterwards
+/// - `swap` before commutative operation is dropped, two pushes followed by `swap` are reordered
+/// - pushing the same value twice becomes push followed by `dup`
+///
+/// "Read afterwards" is a scan to the next store of the slot; once it reaches a jump or a label the
+/// slot may be read on another path, so it counts as read. Windows never span a label, as rules only
+/// match the instructions they list. Rewrites never make the stack deeper than `dup` does;
+/// callers recompute the stack limit.
+pub fn optimize(instructions: Vec<JVMInstruction>) -> (Vec<JVMInstruction>, Stats) {
+    let before = count(&instructions);
+    let mut instructions = instructions;
+
+    loop {
+        let (rewritten, changed) = rewrite_pass(&instructions);
+        instructions = rewritten;
+
+        if !changed {
+            break;
+        }
+    }
+
+    let after = count(&instructions);
+
+    (instructions, Stats { before, after })
+}
+
+fn count(instructions: &[JVMInstruction]) -> usize {
+    instructions
+        .iter()
+        .filter(|instruction| !matches!(instruction, Label(_)))
+        .count()
+}
+
+fn rewrite_pass<'a>(instructions: &[JVMInstruction<'a>]) -> (Vec<JVMInstruction<'a>>, bool) {
+    let mut out = vec![];
+    let mut changed = false;
+    let mut i = 0;
+
+    while i < instructions.len() {
+        if let Some((replacement, consumed)) = rewrite_window(&instructions[i..]) {
+            out.extend(replacement);
+            i += consumed;
+            changed = true;
+        } else {
+            out.push(instructions[i]);
+            i += 1;
+        }
+    }
+
+    (out, changed)
+}
+
+/// Replacement for instructions at the start of `window` and how many of them it replaces
+fn rewrite_window<'a>(window: &[JVMInstruction<'a>]) -> Option<(Vec<JVMInstruction<'a>>, usize)> {
+    match *window {
+        [ILoad(n), push, IAdd, IStore(m), ..] | [push, ILoad(n), IAdd, IStore(m), ..]
+            if n == m && increment(push, false).is_some() =>
+        {
+            Some((vec![IInc(n, increment(push, false)?)], 4))
+        }
+        [ILoad(n), push, ISub, IStore(m), ..] if n == m && increment(push, true).is_some() => {
+            Some((vec![IInc(n, increment(push, true)?)], 4))
+        }
+        [IStore(n), ILoad(m), ..] if n == m => {
+            if is_read_later(n, &window[2..]) {
+                Some((vec![Dup, IStore(n)], 2))
+            } else {
+                Some((vec![], 2))
+            }
+        }
+        [Swap, operation, ..] if is_commutative(operation) => Some((vec![operation], 2)),
+        [first, second, Swap, ..] if is_plain_push(first) && is_plain_push(second) => {
+            Some((vec![second, first], 3))
+        }
+        [Dup, Swap, ..] => Some((vec![Dup], 2)),
+        [first, second, ..] if first == second && is_plain_push(first) => {
+            Some((vec![first, Dup], 2))
+        }
+        _ => None,
+    }
+}
+
+/// Amount for `iinc` adding (or subtracting) constant pushed by instruction, if it fits in a byte
+fn increment(push: JVMInstruction, negate: bool) -> Option<i8> {
+    let amount = match push {
+        IConst(n) => n,
+        BiPush(n) => i32::from(n),
+        _ => return None,
+    };
+
+    let amount = if negate { -amount } else { amount };
+    if amount < i32::from(i8::MIN) || amount > i32::from(i8::MAX) {
+        return None;
+    }
+
+    Some(amount as i8)
+}
+
+fn is_commutative(instruction: JVMInstruction) -> bool {
+    matches!(instruction, IAdd | IMul | IAnd | IOr | IXor)
+}
+
+/// Pushes single value and has no other effect, so it can be reordered with another such push
+fn is_plain_push(instruction: JVMInstruction) -> bool {
+    matches!(
+        instruction,
+        IConst(_) | BiPush(_) | SiPush(_) | Ldc(_) | ILoad(_) | GetStatic
+    )
+}
+
+fn is_read_later(slot: usize, rest: &[JVMInstruction]) -> bool {
+    for instruction in rest.iter() {
+        match *instruction {
+            ILoad(n) | IInc(n, _) if n == slot => return true,
+            IStore(n) if n == slot => return false,
+            Label(_) | Goto(_) | IfICmp(_, _) => return true,
+            IReturn => return false,
+            _ => {}
+        }
+    }
+
+    false
+}
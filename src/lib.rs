@@ -0,0 +1,49 @@
+//! Instant compiler as a library.
+//!
+//! Parses Instant source into `Vec<Box<ast::Stmt>>` and compiles it with one of the backends:
+//! `llvm::compile` producing LLVM IR text, `jvm::compile` producing Jasmin assembly
+//! or `jvm::compile_class` producing binary class file, `x86::compile` producing x86-64 assembly
+//! and `wasm::compile_wat`/`wasm::compile_wasm` producing WebAssembly module.
+//! LLVM and JVM backends work on three-address code from `ir::lower` rather than on the AST.
+//! Functions declared with `fn` become LLVM functions, static methods of the JVM class,
+//! x86 procedures and WebAssembly functions next to the program's `main`.
+//! `interp::run` evaluates program directly and serves as reference for the backends,
+//! `opt::optimize` simplifies program before code generation.
+//! Parser and `semantic::check` report problems as `CompileError`, which can be rendered against
+//! the source; backends expect a program which passed both, as returned by `parse_and_check`.
+
+pub mod ast;
+pub mod classfile;
+pub mod cli;
+pub mod error;
+#[allow(clippy::all, unused_parens)]
+pub mod instant_parser;
+pub mod interp;
+pub mod ir;
+pub mod jvm;
+pub mod jvm_peephole;
+pub mod llvm;
+pub mod llvm_ir;
+pub mod opt;
+pub mod semantic;
+pub mod wasm;
+pub mod x86;
+
+use ast::Stmt;
+pub use error::{CompileError, ErrorKind};
+
+/// Parses whole Instant program into list of statements.
+pub fn parse(source: &str) -> Result<Vec<Box<Stmt>>, CompileError> {
+    instant_parser::ProgramParser::new()
+        .parse(source)
+        .map_err(CompileError::from)
+}
+
+/// Parses program and runs semantic analysis, so that backends only ever see valid programs.
+pub fn parse_and_check(source: &str) -> Result<Vec<Box<Stmt>>, Vec<CompileError>> {
+    let stmts = parse(source).map_err(|error| vec![error])?;
+
+    semantic::check(&stmts)?;
+
+    Ok(stmts)
+}
@@ -1,147 +1,523 @@
-use std::collections::HashMap;
-use std::fmt;
+use std::collections::{HashMap, HashSet};
 
-use crate::ast::{Expr, Opcode, Stmt};
+use crate::ast::{Opcode, Relation, Stmt};
+use crate::ir::{self, Label, Operand, Temp};
+use crate::llvm_ir::{Block, Function, Global, Instruction, Local, Module, Type, Value};
 
-pub fn compile(stmts: &Vec<Box<Stmt>>) -> String {
-    let mut state = LLVMState::new();
+/// Compiles program keeping every variable in `alloca` slot, loading and storing it on each use.
+pub fn compile(stmts: &[Box<Stmt>]) -> String {
+    compile_with_mode(stmts, Mode::Memory)
+}
+
+/// Compiles program in SSA form: each variable names the value last assigned to it, with phi nodes
+/// where branches assigning it meet, so no memory is used at all.
+pub fn compile_ssa(stmts: &[Box<Stmt>]) -> String {
+    compile_with_mode(stmts, Mode::Ssa)
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Mode {
+    Memory,
+    Ssa,
+}
+
+/// Common subexpressions are eliminated on the IR; loads are numbered here as well, so a variable
+/// is loaded at most once between two stores. User functions are defined before `main` and
+/// named after their source names; `main` takes precedence, so a user function called `main`
+/// becomes `@main.1`. Program must have passed `semantic::check`, as for `ir::lower`.
+fn compile_with_mode(stmts: &[Box<Stmt>], mode: Mode) -> String {
+    let program = ir::eliminate_common_subexpressions(ir::lower(stmts));
+
+    let mut module = Module::new();
+    let print_int = module.declare("printInt", Type::Void, vec![Type::I32]);
+    let main = module.function("main", Type::I32, vec![]);
+
+    let mut functions = vec![];
+    let mut globals = HashMap::new();
+    for function in program.functions.iter() {
+        let params = vec![Type::I32; function.params.len()];
+        let llvm_function = module.function(&function.name, Type::I32, params);
+
+        globals.insert(function.name.as_str(), llvm_function.global());
+        functions.push(llvm_function);
+    }
+
+    for (function, llvm_function) in program.functions.iter().zip(functions) {
+        let mut state = LLVMState::new(mode, llvm_function, &function.body, print_int, &globals);
+
+        for param in function.params.iter() {
+            let value = Value::Local(state.function.add_param(param));
+            state.assign(param, value);
+        }
+
+        module.add(state.compile_body(&function.body));
+    }
 
-    stmts
-        .iter()
-        .for_each(|stmt| compile_stmt(&stmt, &mut state));
+    let state = LLVMState::new(mode, main, &program.main, print_int, &globals);
+    module.add(state.compile_body(&program.main));
 
-    state.generate_code()
+    module.to_string()
 }
 
-struct LLVMState {
+struct LLVMState<'a> {
+    mode: Mode,
     register_count: usize,
-    instructions: Vec<String>,
-    var_loc_map: HashMap<String, i32>,
+    print_int: Global,
+    globals: &'a HashMap<&'a str, Global>, // user functions
+    function: Function,
+    block: Block, // block being filled
+    label_blocks: HashMap<Label, Block>,
+    ssa: SsaBuilder,
+    var_loc_map: HashMap<String, Local>,
     var_loc_counts: HashMap<String, i32>,
+    var_versions: HashMap<String, usize>, // number of assignments so far, invalidates loaded values
+    loaded_values: HashMap<(String, usize), Value>, // loads done in current block
+    temp_values: HashMap<Temp, Value>,
 }
 
-impl LLVMState {
-    fn new() -> LLVMState {
+impl<'a> LLVMState<'a> {
+    /// Creates a block for every label up front, in program order, and counts jumps to it
+    fn new(
+        mode: Mode,
+        mut function: Function,
+        body: &ir::Body,
+        print_int: Global,
+        globals: &'a HashMap<&'a str, Global>,
+    ) -> LLVMState<'a> {
+        let mut ssa = SsaBuilder::new(function.entry());
+
+        let mut label_blocks = HashMap::new();
+        for instruction in body.instructions.iter() {
+            if let ir::Instruction::Label(label) = instruction {
+                label_blocks.insert(*label, function.add_block(&label.to_string()));
+            }
+        }
+        for target in body.instructions.iter().flat_map(|instruction| instruction.targets()) {
+            ssa.expect_jump(label_blocks[&target]);
+        }
+
         LLVMState {
+            mode,
             register_count: 0,
-            instructions: vec![],
+            print_int,
+            globals,
+            block: function.entry(),
+            function,
+            label_blocks,
+            ssa,
             var_loc_map: HashMap::new(),
             var_loc_counts: HashMap::new(),
+            var_versions: HashMap::new(),
+            loaded_values: HashMap::new(),
+            temp_values: HashMap::new(),
+        }
+    }
+
+    /// Function bodies end with `return`, only `main` runs off its end and returns 0.
+    /// Phi nodes are only complete once the whole body is compiled.
+    fn compile_body(mut self, body: &ir::Body) -> Function {
+        for instruction in body.instructions.iter() {
+            compile_instruction(instruction, &mut self);
+        }
+
+        if !matches!(body.instructions.last(), Some(ir::Instruction::Return(_))) {
+            self.push(Instruction::Ret(Value::Const(0)));
+        }
+
+        for phi in self.ssa.phis.into_iter() {
+            self.function.push_phi(phi.block, phi.dest, phi.incoming);
         }
+
+        self.function
+    }
+
+    fn push(&mut self, instruction: Instruction) {
+        self.function.push(self.block, instruction);
     }
 
-    fn generate_code(&self) -> String {
-        let instructions = self.instructions.join("\n\t");
+    /// Terminates current block with jump to one of `targets`
+    fn jump(&mut self, instruction: Instruction, targets: &[Block]) {
+        self.push(instruction);
 
-        format!(
-            "{}{}{}",
-            String::from("declare void @printInt(i32)\ndefine i32 @main() {\n\t"),
-            instructions,
-            String::from("\n\tret i32 0\n}\n")
-        )
+        for target in targets.iter() {
+            self.ssa.add_jump(self.block, *target, &mut self.function);
+        }
+    }
+
+    fn get_next_register(&mut self) -> Local {
+        self.register_count += 1;
+        self.function.local(&format!("r{}", self.register_count))
     }
 
-    fn get_next_register_number(&mut self) -> usize {
-        self.register_count = self.register_count + 1;
-        self.register_count
+    fn alloca(&mut self, ident: &str) -> Local {
+        let ptr = self.function.local(&format!("loc_{}", ident));
+        self.function.push_alloca(ptr);
+
+        ptr
     }
 
-    fn alloca(&mut self, ident: &String) {
-        self.instructions
-            .push(format!("%loc_{} = alloca i32", ident))
+    /// Parameters are assigned this way too, on entry
+    fn assign(&mut self, ident: &str, value: Value) {
+        if self.mode == Mode::Ssa {
+            self.ssa.write(ident, self.block, value);
+            return;
+        }
+
+        *self.var_versions.entry(ident.to_string()).or_insert(0) += 1;
+
+        let ptr = match self.var_loc_map.get(ident) {
+            Some(ptr) => *ptr,
+            None => {
+                let ptr = self.alloca(ident);
+                self.var_loc_map.insert(ident.to_string(), ptr);
+                ptr
+            }
+        };
+
+        self.store(value, ptr);
     }
 
-    fn store(&mut self, result: LLVMResult, ident: &String) {
-        self.instructions
-            .push(format!("store i32 {}, i32* %loc_{}", result, ident));
+    fn store(&mut self, value: Value, ptr: Local) {
+        self.push(Instruction::Store { value, ptr });
     }
 
-    fn print(&mut self, result: LLVMResult) {
-        self.instructions
-            .push(format!("call void @printInt(i32 {})", result));
+    fn print(&mut self, value: Value) {
+        let callee = self.print_int;
+        self.push(Instruction::Call {
+            dest: None,
+            callee,
+            args: vec![value],
+        });
     }
 
-    fn load(&mut self, result: &LLVMResult, ident: &String) {
-        self.instructions
-            .push(format!("{} = load i32, i32* %loc_{}", result, ident))
+    fn load(&mut self, dest: Local, ptr: Local) {
+        self.push(Instruction::Load { dest, ptr });
     }
 
-    fn arithmetic(&mut self, result: &LLVMResult, opcode: &Opcode, l: &LLVMResult, r: &LLVMResult) {
-        self.instructions
-            .push(format!("{} = {} i32 {}, {}", result, opcode, l, r));
+    fn arithmetic(&mut self, dest: Local, opcode: Opcode, l: Value, r: Value) {
+        self.push(Instruction::BinOp { dest, opcode, l, r });
     }
-}
 
-enum LLVMResult {
-    Constant(i32),
-    Register(usize),
-    RegisterVar(String, i32),
-}
+    /// `sdiv` and `srem` of `i32::MIN` by -1 are undefined in LLVM, so divisor -1 is replaced by 1
+    /// and the quotient negated, giving `0 - dividend` as the interpreter does; remainder by 1 is 0
+    fn division(&mut self, opcode: Opcode, l: Value, r: Value) -> Value {
+        let by_minus_one = match r {
+            Value::Const(-1) if opcode == Opcode::Rem => return Value::Const(0),
+            Value::Const(-1) => {
+                let result = self.get_next_register();
+                self.arithmetic(result, Opcode::Sub, Value::Const(0), l);
+                return Value::Local(result);
+            }
+            Value::Const(_) => None,
+            Value::Local(_) => {
+                let cond = self.get_next_register();
+                self.push(Instruction::ICmp {
+                    dest: cond,
+                    relation: Relation::Eq,
+                    l: r,
+                    r: Value::Const(-1),
+                });
+                Some(cond)
+            }
+        };
+
+        let divisor = match by_minus_one {
+            Some(cond) => {
+                let divisor = self.get_next_register();
+                self.push(Instruction::Select {
+                    dest: divisor,
+                    cond,
+                    then: Value::Const(1),
+                    otherwise: r,
+                });
+                Value::Local(divisor)
+            }
+            None => r,
+        };
 
-impl fmt::Display for LLVMResult {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        use LLVMResult::*;
-        match self {
-            Constant(c) => write!(f, "{}", c),
-            Register(r) => write!(f, "%r{}", r),
-            RegisterVar(ident, count) => match ident.as_ref() {
-                "r" => write!(f, "%var__{}{}", ident, count),
-                _ => write!(f, "%{}{}", ident, count),
-            },
+        let quotient = self.get_next_register();
+        self.arithmetic(quotient, opcode, l, divisor);
+
+        match by_minus_one {
+            Some(cond) if opcode == Opcode::Div => {
+                let negated = self.get_next_register();
+                let result = self.get_next_register();
+                self.arithmetic(negated, Opcode::Sub, Value::Const(0), Value::Local(quotient));
+                self.push(Instruction::Select {
+                    dest: result,
+                    cond,
+                    then: Value::Local(negated),
+                    otherwise: Value::Local(quotient),
+                });
+                Value::Local(result)
+            }
+            _ => Value::Local(quotient),
         }
     }
 }
 
-fn compile_stmt(stmt: &Stmt, state: &mut LLVMState) {
-    use Stmt::*;
+fn compile_instruction(instruction: &ir::Instruction, state: &mut LLVMState) {
+    match instruction {
+        ir::Instruction::BinOp { dest, opcode, l, r } => {
+            let (l, mut r) = (compile_operand(l, state), compile_operand(r, state));
 
-    match stmt {
-        SAss(ident, expr) => {
-            if let 0 = state.var_loc_map.entry(ident.clone())
-                .and_modify(|c| *c += 1)
-                .or_insert(0) {
-                state.alloca(ident);
+            // shl/ashr by 32 or more is poison in LLVM, Instant takes shift amount modulo 32
+            if let Opcode::Shl | Opcode::Shr = opcode {
+                r = match r {
+                    Value::Const(n) => Value::Const(n & 31),
+                    Value::Local(_) => {
+                        let masked = state.get_next_register();
+                        state.arithmetic(masked, Opcode::And, r, Value::Const(31));
+                        Value::Local(masked)
+                    }
+                };
             }
 
-            let result = compile_expr(&expr, state);
+            let result = if opcode.is_division() {
+                state.division(*opcode, l, r)
+            } else {
+                let result = state.get_next_register();
+                state.arithmetic(result, *opcode, l, r);
+                Value::Local(result)
+            };
 
-            state.store(result, ident);
+            state.temp_values.insert(*dest, result);
         }
-        SExpr(expr) => {
-            let result = compile_expr(&expr, state);
+        ir::Instruction::Neg { dest, value } => {
+            let value = compile_operand(value, state);
+            let result = state.get_next_register();
 
-            state.print(result);
+            state.arithmetic(result, Opcode::Sub, Value::Const(0), value);
+            state.temp_values.insert(*dest, Value::Local(result));
+        }
+        ir::Instruction::Assign { dest, value } => {
+            let value = compile_operand(value, state);
+
+            state.assign(dest, value);
+        }
+        ir::Instruction::Print(value) => {
+            let value = compile_operand(value, state);
+
+            state.print(value);
+        }
+        ir::Instruction::Call {
+            dest,
+            function,
+            args,
+        } => {
+            let args = args.iter().map(|arg| compile_operand(arg, state)).collect();
+            let result = state.get_next_register();
+            let callee = state.globals[function.as_str()];
+
+            state.push(Instruction::Call {
+                dest: Some(result),
+                callee,
+                args,
+            });
+            state.temp_values.insert(*dest, Value::Local(result));
+        }
+        ir::Instruction::Return(value) => {
+            let value = compile_operand(value, state);
+
+            state.push(Instruction::Ret(value));
+        }
+        ir::Instruction::Label(label) => {
+            state.block = state.label_blocks[label];
+            state.loaded_values.clear();
+        }
+        ir::Instruction::Jump(label) => {
+            let target = state.label_blocks[label];
+
+            state.jump(Instruction::Br(target), &[target]);
+        }
+        ir::Instruction::Branch {
+            relation,
+            l,
+            r,
+            then,
+            otherwise,
+        } => {
+            let (l, r) = (compile_operand(l, state), compile_operand(r, state));
+            let cond = state.get_next_register();
+            let (then, otherwise) = (state.label_blocks[then], state.label_blocks[otherwise]);
+
+            state.push(Instruction::ICmp {
+                dest: cond,
+                relation: *relation,
+                l,
+                r,
+            });
+            state.jump(
+                Instruction::CondBr {
+                    cond,
+                    then,
+                    otherwise,
+                },
+                &[then, otherwise],
+            );
         }
     }
 }
 
-fn compile_expr<'a>(expr: &Expr, state: &'a mut LLVMState) -> LLVMResult {
-    use Expr::*;
-    use LLVMResult::*;
+/// IR guarantees variables are assigned and temporaries computed before they are read
+fn compile_operand(operand: &Operand, state: &mut LLVMState) -> Value {
+    match operand {
+        Operand::Const(n) => Value::Const(*n),
+        Operand::Temp(temp) => state.temp_values[temp],
+        Operand::Var(ident) => {
+            if state.mode == Mode::Ssa {
+                return state.ssa.read(ident, state.block, &mut state.function);
+            }
+
+            // Value loaded in this block since the last store can be reused
+            let version = state.var_versions.get(ident).copied().unwrap_or(0);
+            if let Some(value) = state.loaded_values.get(&(ident.clone(), version)) {
+                return *value;
+            }
 
-    match expr {
-        Number(n) => LLVMResult::Constant(*n),
-        Ident(ident) => {
-            let count = state
+            let count = *state
                 .var_loc_counts
                 .entry(ident.clone())
                 .and_modify(|c| *c += 1)
                 .or_insert(0);
 
-            let result = RegisterVar(ident.clone(), *count);
+            let dest = state.function.local(&format!("{}{}", ident, count));
+            let ptr = state.var_loc_map[ident];
+
+            state.load(dest, ptr);
+
+            let value = Value::Local(dest);
+            state.loaded_values.insert((ident.clone(), version), value);
+
+            value
+        }
+    }
+}
+
+struct Phi {
+    block: Block,
+    dest: Local,
+    incoming: Vec<(Value, Block)>,
+}
+
+/// Builds SSA form while code is generated, after Braun et al., "Simple and Efficient Construction
+/// of Static Single Assignment Form". Variable read in a block which doesn't assign it takes its value
+/// from the predecessors, through a phi node when they disagree. Block is sealed once all jumps to it
+/// are compiled; a read in a block which isn't sealed yet gets a phi whose operands are filled in then.
+struct SsaBuilder {
+    definitions: HashMap<(String, Block), Value>,
+    predecessors: HashMap<Block, Vec<Block>>,
+    unfilled: HashMap<Block, usize>, // jumps to block not compiled yet, block is sealed at 0
+    incomplete: HashMap<Block, Vec<(String, usize)>>,
+    visiting: HashSet<(String, Block)>,
+    placeholders: HashMap<(String, Block), usize>,
+    phis: Vec<Phi>,
+}
 
-            state.load(&result, ident);
+impl SsaBuilder {
+    fn new(entry: Block) -> SsaBuilder {
+        let mut predecessors = HashMap::new();
+        predecessors.insert(entry, vec![]);
 
-            result
+        SsaBuilder {
+            definitions: HashMap::new(),
+            predecessors,
+            unfilled: HashMap::new(),
+            incomplete: HashMap::new(),
+            visiting: HashSet::new(),
+            placeholders: HashMap::new(),
+            phis: vec![],
         }
-        Op(l_expr, opcode, r_expr) => {
-            let (l, r) = (compile_expr(l_expr, state), compile_expr(r_expr, state));
+    }
+
+    fn expect_jump(&mut self, target: Block) {
+        *self.unfilled.entry(target).or_insert(0) += 1;
+        self.predecessors.entry(target).or_default();
+    }
+
+    fn add_jump(&mut self, from: Block, target: Block, function: &mut Function) {
+        self.predecessors.get_mut(&target).unwrap().push(from);
 
-            let result = Register(state.get_next_register_number());
+        let unfilled = self.unfilled.get_mut(&target).unwrap();
+        *unfilled -= 1;
 
-            state.arithmetic(&result, opcode, &l, &r);
+        if *unfilled == 0 {
+            for (ident, phi) in self.incomplete.remove(&target).unwrap_or_default() {
+                self.phis[phi].incoming = self.read_predecessors(&ident, target, function);
+            }
+        }
+    }
 
-            result
+    fn write(&mut self, ident: &str, block: Block, value: Value) {
+        self.definitions.insert((ident.to_string(), block), value);
+    }
+
+    /// `ir::lower` guarantees variable is assigned on every path to the read
+    fn read(&mut self, ident: &str, block: Block, function: &mut Function) -> Value {
+        let key = (ident.to_string(), block);
+        if let Some(value) = self.definitions.get(&key) {
+            return *value;
         }
+
+        let predecessors = self.predecessors[&block].clone();
+
+        let value = if self.unfilled.get(&block).copied().unwrap_or(0) > 0 {
+            let phi = self.new_phi(ident, block, function);
+            self.incomplete.entry(block).or_default().push((ident.to_string(), phi));
+
+            Value::Local(self.phis[phi].dest)
+        } else if predecessors.len() == 1 {
+            self.read(ident, predecessors[0], function)
+        } else if self.visiting.contains(&key) {
+            // Came back around a loop: the read which started there fills the phi in
+            let phi = self.new_phi(ident, block, function);
+            self.placeholders.insert(key.clone(), phi);
+
+            Value::Local(self.phis[phi].dest)
+        } else {
+            self.visiting.insert(key.clone());
+            let incoming = self.read_predecessors(ident, block, function);
+            self.visiting.remove(&key);
+
+            match self.placeholders.remove(&key) {
+                Some(phi) => {
+                    self.phis[phi].incoming = incoming;
+                    Value::Local(self.phis[phi].dest)
+                }
+                None if incoming.iter().all(|(value, _)| *value == incoming[0].0) => incoming[0].0,
+                None => {
+                    let phi = self.new_phi(ident, block, function);
+                    self.phis[phi].incoming = incoming;
+                    Value::Local(self.phis[phi].dest)
+                }
+            }
+        };
+
+        self.definitions.insert(key, value);
+
+        value
+    }
+
+    fn read_predecessors(
+        &mut self,
+        ident: &str,
+        block: Block,
+        function: &mut Function,
+    ) -> Vec<(Value, Block)> {
+        self.predecessors[&block]
+            .clone()
+            .into_iter()
+            .map(|predecessor| (self.read(ident, predecessor, function), predecessor))
+            .collect()
+    }
+
+    fn new_phi(&mut self, ident: &str, block: Block, function: &mut Function) -> usize {
+        self.phis.push(Phi {
+            block,
+            dest: function.local(ident),
+            incoming: vec![],
+        });
+
+        self.phis.len() - 1
     }
 }
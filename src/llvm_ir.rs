@@ -0,0 +1,478 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::ast::{Opcode, Relation};
+
+/// Small typed model of LLVM IR used by the LLVM backend.
+///
+/// Values, blocks and functions are referred to by ids; their names are kept in one table per
+/// namespace (function locals, module globals) which makes every name unique when it is created,
+/// so names derived from program identifiers can't collide with each other or with compiler temporaries.
+pub struct Module {
+    globals: Vec<GlobalInfo>,
+    names: Names,
+    functions: Vec<Function>,
+}
+
+struct GlobalInfo {
+    name: String,
+    return_type: Type,
+    params: Vec<Type>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Type {
+    Void,
+    I32,
+}
+
+/// Function declared or defined in module
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Global(usize);
+
+/// Named local value of function (result of instruction)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Local(usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Block(usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Value {
+    Const(i32),
+    Local(Local),
+}
+
+#[derive(Debug, Clone)]
+pub enum Instruction {
+    Alloca {
+        dest: Local,
+    },
+    Load {
+        dest: Local,
+        ptr: Local,
+    },
+    Store {
+        value: Value,
+        ptr: Local,
+    },
+    BinOp {
+        dest: Local,
+        opcode: Opcode,
+        l: Value,
+        r: Value,
+    },
+    ICmp {
+        dest: Local,
+        relation: Relation,
+        l: Value,
+        r: Value,
+    },
+    Phi {
+        dest: Local,
+        incoming: Vec<(Value, Block)>,
+    },
+    Select {
+        dest: Local,
+        cond: Local, // i1 result of icmp
+        then: Value,
+        otherwise: Value,
+    },
+    Call {
+        dest: Option<Local>,
+        callee: Global,
+        args: Vec<Value>,
+    },
+    Br(Block),
+    CondBr {
+        cond: Local, // i1 result of icmp
+        then: Block,
+        otherwise: Block,
+    },
+    Ret(Value),
+}
+
+impl Instruction {
+    fn is_terminator(&self) -> bool {
+        matches!(self, Instruction::Br(_) | Instruction::CondBr { .. } | Instruction::Ret(_))
+    }
+}
+
+pub struct Function {
+    global: Global,
+    params: Vec<Local>, // names of parameters, when given
+    names: Names,
+    // Local and Block ids index the same table, as values and labels share function's namespace
+    local_names: Vec<String>,
+    blocks: Vec<BasicBlock>,
+}
+
+struct BasicBlock {
+    label: usize,
+    instructions: Vec<Instruction>,
+}
+
+/// Hands out unique names: a name already taken gets `.N` suffix with the first free N.
+struct Names {
+    used: HashSet<String>,
+}
+
+impl Names {
+    fn new() -> Names {
+        Names {
+            used: HashSet::new(),
+        }
+    }
+
+    fn fresh(&mut self, name: &str) -> String {
+        let mut unique = name.to_string();
+        let mut suffix = 0;
+
+        while self.used.contains(&unique) {
+            suffix += 1;
+            unique = format!("{}.{}", name, suffix);
+        }
+
+        self.used.insert(unique.clone());
+        unique
+    }
+}
+
+impl Module {
+    pub fn new() -> Module {
+        Module {
+            globals: vec![],
+            names: Names::new(),
+            functions: vec![],
+        }
+    }
+
+    /// External function, e.g. from runtime
+    pub fn declare(&mut self, name: &str, return_type: Type, params: Vec<Type>) -> Global {
+        let name = self.names.fresh(name);
+        self.globals.push(GlobalInfo {
+            name,
+            return_type,
+            params,
+        });
+
+        Global(self.globals.len() - 1)
+    }
+
+    /// Creates empty function with entry block; it's emitted once passed to `add`.
+    pub fn function(&mut self, name: &str, return_type: Type, params: Vec<Type>) -> Function {
+        let global = self.declare(name, return_type, params);
+
+        let mut function = Function {
+            global,
+            params: vec![],
+            names: Names::new(),
+            local_names: vec![],
+            blocks: vec![],
+        };
+        function.add_block("entry");
+
+        function
+    }
+
+    pub fn add(&mut self, function: Function) {
+        self.functions.push(function);
+    }
+}
+
+impl Default for Module {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Function {
+    pub fn global(&self) -> Global {
+        self.global
+    }
+
+    /// New value named after `name`, made unique within function
+    pub fn local(&mut self, name: &str) -> Local {
+        Local(self.new_name(name))
+    }
+
+    /// Names the next parameter, in declaration order
+    pub fn add_param(&mut self, name: &str) -> Local {
+        let param = self.local(name);
+        self.params.push(param);
+
+        param
+    }
+
+    pub fn add_block(&mut self, name: &str) -> Block {
+        let label = self.new_name(name);
+        self.blocks.push(BasicBlock {
+            label,
+            instructions: vec![],
+        });
+
+        Block(self.blocks.len() - 1)
+    }
+
+    pub fn entry(&self) -> Block {
+        Block(0)
+    }
+
+    pub fn push(&mut self, block: Block, instruction: Instruction) {
+        self.blocks[block.0].instructions.push(instruction);
+    }
+
+    /// Phi nodes have to open the block, so they go after the ones already there
+    pub fn push_phi(&mut self, block: Block, dest: Local, incoming: Vec<(Value, Block)>) {
+        let instructions = &mut self.blocks[block.0].instructions;
+        let position = instructions
+            .iter()
+            .take_while(|instruction| matches!(instruction, Instruction::Phi { .. }))
+            .count();
+
+        instructions.insert(position, Instruction::Phi { dest, incoming });
+    }
+
+    /// Allocas go to the entry block, even once it has been terminated, so every slot is
+    /// allocated once per call whichever block first stores to it.
+    pub fn push_alloca(&mut self, dest: Local) {
+        let entry = self.entry();
+        let instructions = &mut self.blocks[entry.0].instructions;
+        let position = match instructions.last() {
+            Some(last) if last.is_terminator() => instructions.len() - 1,
+            _ => instructions.len(),
+        };
+
+        instructions.insert(position, Instruction::Alloca { dest });
+    }
+
+    fn new_name(&mut self, name: &str) -> usize {
+        let name = self.names.fresh(name);
+        self.local_names.push(name);
+
+        self.local_names.len() - 1
+    }
+
+    fn local_name(&self, local: Local) -> LocalName<'_> {
+        LocalName(&self.local_names[local.0])
+    }
+
+    fn value<'a>(&'a self, value: &'a Value) -> ValueName<'a> {
+        ValueName(self, value)
+    }
+
+    fn block_name(&self, block: Block) -> LocalName<'_> {
+        LocalName(&self.local_names[self.blocks[block.0].label])
+    }
+
+    fn fmt_instruction(
+        &self,
+        f: &mut fmt::Formatter,
+        module: &Module,
+        instruction: &Instruction,
+    ) -> fmt::Result {
+        use Instruction::*;
+
+        match instruction {
+            Alloca { dest } => write!(f, "{} = alloca i32", self.local_name(*dest)),
+            Load { dest, ptr } => write!(
+                f,
+                "{} = load i32, i32* {}",
+                self.local_name(*dest),
+                self.local_name(*ptr)
+            ),
+            Store { value, ptr } => write!(
+                f,
+                "store i32 {}, i32* {}",
+                self.value(value),
+                self.local_name(*ptr)
+            ),
+            BinOp { dest, opcode, l, r } => write!(
+                f,
+                "{} = {} i32 {}, {}",
+                self.local_name(*dest),
+                opcode,
+                self.value(l),
+                self.value(r)
+            ),
+            ICmp {
+                dest,
+                relation,
+                l,
+                r,
+            } => write!(
+                f,
+                "{} = icmp {} i32 {}, {}",
+                self.local_name(*dest),
+                relation,
+                self.value(l),
+                self.value(r)
+            ),
+            Phi { dest, incoming } => {
+                write!(f, "{} = phi i32 ", self.local_name(*dest))?;
+                for (i, (value, block)) in incoming.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "[ {}, {} ]", self.value(value), self.block_name(*block))?;
+                }
+                Ok(())
+            }
+            Select {
+                dest,
+                cond,
+                then,
+                otherwise,
+            } => write!(
+                f,
+                "{} = select i1 {}, i32 {}, i32 {}",
+                self.local_name(*dest),
+                self.local_name(*cond),
+                self.value(then),
+                self.value(otherwise)
+            ),
+            Call { dest, callee, args } => {
+                let global = &module.globals[callee.0];
+                if let Some(dest) = dest {
+                    write!(f, "{} = ", self.local_name(*dest))?;
+                }
+                write!(f, "call {} {}(", global.return_type, GlobalName(&global.name))?;
+                for (i, (arg, ty)) in args.iter().zip(global.params.iter()).enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{} {}", ty, self.value(arg))?;
+                }
+                write!(f, ")")
+            }
+            Br(block) => write!(f, "br label {}", self.block_name(*block)),
+            CondBr {
+                cond,
+                then,
+                otherwise,
+            } => write!(
+                f,
+                "br i1 {}, label {}, label {}",
+                self.local_name(*cond),
+                self.block_name(*then),
+                self.block_name(*otherwise)
+            ),
+            Ret(value) => write!(f, "ret i32 {}", self.value(value)),
+        }
+    }
+
+    fn fmt(&self, f: &mut fmt::Formatter, module: &Module) -> fmt::Result {
+        let global = &module.globals[self.global.0];
+        let params: Vec<String> = global
+            .params
+            .iter()
+            .enumerate()
+            .map(|(i, ty)| match self.params.get(i) {
+                Some(param) => format!("{} {}", ty, self.local_name(*param)),
+                None => ty.to_string(),
+            })
+            .collect();
+
+        writeln!(
+            f,
+            "define {} {}({}) {{",
+            global.return_type,
+            GlobalName(&global.name),
+            params.join(", ")
+        )?;
+
+        for block in self.blocks.iter() {
+            writeln!(f, "{}:", escape(&self.local_names[block.label]))?;
+            for instruction in block.instructions.iter() {
+                write!(f, "\t")?;
+                self.fmt_instruction(f, module, instruction)?;
+                writeln!(f)?;
+            }
+        }
+
+        writeln!(f, "}}")
+    }
+}
+
+impl fmt::Display for Module {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let defined: HashSet<Global> = self.functions.iter().map(|function| function.global).collect();
+
+        for (i, global) in self.globals.iter().enumerate() {
+            if defined.contains(&Global(i)) {
+                continue;
+            }
+
+            let params: Vec<String> = global.params.iter().map(|ty| ty.to_string()).collect();
+            writeln!(
+                f,
+                "declare {} {}({})",
+                global.return_type,
+                GlobalName(&global.name),
+                params.join(", ")
+            )?;
+        }
+
+        self.functions
+            .iter()
+            .try_for_each(|function| function.fmt(f, self))
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Type::Void => write!(f, "void"),
+            Type::I32 => write!(f, "i32"),
+        }
+    }
+}
+
+struct LocalName<'a>(&'a str);
+
+impl fmt::Display for LocalName<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "%{}", escape(self.0))
+    }
+}
+
+struct ValueName<'a>(&'a Function, &'a Value);
+
+impl fmt::Display for ValueName<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.1 {
+            Value::Const(n) => write!(f, "{}", n),
+            Value::Local(local) => write!(f, "{}", self.0.local_name(*local)),
+        }
+    }
+}
+
+struct GlobalName<'a>(&'a str);
+
+impl fmt::Display for GlobalName<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "@{}", escape(self.0))
+    }
+}
+
+/// Names matching `[-a-zA-Z$._][-a-zA-Z$._0-9]*` are printed as they are, others are quoted with
+/// every byte outside printable ASCII (and `"`, `\`) written as `\XX`.
+fn escape(name: &str) -> String {
+    let is_plain = |c: char| c.is_ascii_alphanumeric() || "-$._".contains(c);
+    let starts_with_digit = name.starts_with(|c: char| c.is_ascii_digit());
+
+    if !name.is_empty() && !starts_with_digit && name.chars().all(is_plain) {
+        return name.to_string();
+    }
+
+    let escaped: String = name
+        .bytes()
+        .map(|byte| match byte {
+            b'"' | b'\\' => format!("\\{:02X}", byte),
+            0x20..=0x7e => (byte as char).to_string(),
+            _ => format!("\\{:02X}", byte),
+        })
+        .collect();
+
+    format!("\"{}\"", escaped)
+}
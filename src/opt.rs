@@ -0,0 +1,331 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::ast::{Cond, Expr, Function, Opcode, Stmt};
+use crate::interp;
+
+/// Optimisations run on AST before any backend (enabled with `-O`).
+/// Returns optimised program and number of dead stores removed from it.
+pub fn optimize(stmts: Vec<Box<Stmt>>) -> (Vec<Box<Stmt>>, usize) {
+    eliminate_dead_stores(propagate_constants(stmts))
+}
+
+/// Tracks variables holding known constants through the program and substitutes them into later
+/// expressions, folding each expression on the way (see `fold_expr`). Assignments themselves are kept.
+/// After `if` a variable is known only if both branches leave it with the same constant; variables
+/// assigned anywhere in `while` body are unknown in the whole loop and after it.
+/// Function bodies start with nothing known, calls leave caller's variables untouched.
+pub fn propagate_constants(stmts: Vec<Box<Stmt>>) -> Vec<Box<Stmt>> {
+    propagate_in_block(stmts, &mut HashMap::new())
+}
+
+#[allow(clippy::vec_box)]
+fn propagate_in_block(stmts: Vec<Box<Stmt>>, known: &mut HashMap<String, i32>) -> Vec<Box<Stmt>> {
+    stmts
+        .into_iter()
+        .map(|stmt| {
+            Box::new(match *stmt {
+                Stmt::SAss(ident, expr) => {
+                    let expr = fold_expr(substitute(*expr, known));
+
+                    match *expr {
+                        Expr::Number(n) => known.insert(ident.clone(), n),
+                        _ => known.remove(&ident),
+                    };
+
+                    Stmt::SAss(ident, expr)
+                }
+                Stmt::SExpr(expr) => Stmt::SExpr(fold_expr(substitute(*expr, known))),
+                Stmt::SIf(cond, then, otherwise) => {
+                    let cond = map_exprs(*cond, &mut |expr| fold_expr(substitute(expr, known)));
+
+                    let mut known_then = known.clone();
+                    let then = propagate_in_block(then, &mut known_then);
+                    let otherwise = propagate_in_block(otherwise, known);
+
+                    known.retain(|ident, n| known_then.get(ident) == Some(n));
+
+                    Stmt::SIf(cond, then, otherwise)
+                }
+                Stmt::SWhile(cond, body) => {
+                    let mut assigned = HashSet::new();
+                    collect_assigned(&body, &mut assigned);
+                    known.retain(|ident, _| !assigned.contains(ident));
+
+                    let cond = map_exprs(*cond, &mut |expr| fold_expr(substitute(expr, known)));
+                    let body = propagate_in_block(body, &mut known.clone());
+
+                    Stmt::SWhile(cond, body)
+                }
+                Stmt::SFn(function) => {
+                    Stmt::SFn(map_body(*function, |body| propagate_in_block(body, &mut HashMap::new())))
+                }
+                Stmt::SReturn(expr, span) => Stmt::SReturn(fold_expr(substitute(*expr, known)), span),
+            })
+        })
+        .collect()
+}
+
+/// Liveness analysis over the statement list removing assignments whose value is never read.
+/// Assignments which could fail with division by zero or call a function are kept; function bodies
+/// are analysed on their own, nothing is live after `return`. Returns number of removed statements.
+pub fn eliminate_dead_stores(stmts: Vec<Box<Stmt>>) -> (Vec<Box<Stmt>>, usize) {
+    let mut removed = 0;
+    let kept = eliminate_in_block(stmts, &mut HashSet::new(), &mut removed);
+
+    (kept, removed)
+}
+
+/// Backward pass over block given variables live after it; leaves variables live before it in `live`.
+/// Variable is live before `if` when it's live at the start of either branch or read by the condition.
+/// Variables live before `while` are found by iterating over its body until they stop changing.
+#[allow(clippy::vec_box)]
+fn eliminate_in_block(
+    stmts: Vec<Box<Stmt>>,
+    live: &mut HashSet<String>,
+    removed: &mut usize,
+) -> Vec<Box<Stmt>> {
+    let mut kept = vec![];
+
+    for stmt in stmts.into_iter().rev() {
+        let stmt = match *stmt {
+            Stmt::SAss(ident, expr) => {
+                if !live.contains(&ident) && !may_fail(&expr) {
+                    *removed += 1;
+                    continue;
+                }
+
+                live.remove(&ident);
+                collect_vars(&expr, live);
+
+                Stmt::SAss(ident, expr)
+            }
+            Stmt::SExpr(expr) => {
+                collect_vars(&expr, live);
+
+                Stmt::SExpr(expr)
+            }
+            Stmt::SIf(cond, then, otherwise) => {
+                let mut live_then = live.clone();
+                let then = eliminate_in_block(then, &mut live_then, removed);
+                let otherwise = eliminate_in_block(otherwise, live, removed);
+
+                live.extend(live_then);
+                collect_cond_vars(&cond, live);
+
+                Stmt::SIf(cond, then, otherwise)
+            }
+            Stmt::SWhile(cond, body) => {
+                // Condition is evaluated before body and after each iteration
+                let mut live_before = live.clone();
+                collect_cond_vars(&cond, &mut live_before);
+
+                loop {
+                    let mut live_body = live_before.clone();
+                    eliminate_in_block(body.clone(), &mut live_body, &mut 0);
+
+                    if live_body.is_subset(&live_before) {
+                        break;
+                    }
+                    live_before.extend(live_body);
+                }
+
+                let body = eliminate_in_block(body, &mut live_before.clone(), removed);
+                *live = live_before;
+
+                Stmt::SWhile(cond, body)
+            }
+            Stmt::SFn(function) => Stmt::SFn(map_body(*function, |body| {
+                eliminate_in_block(body, &mut HashSet::new(), removed)
+            })),
+            Stmt::SReturn(expr, span) => {
+                live.clear();
+                collect_vars(&expr, live);
+
+                Stmt::SReturn(expr, span)
+            }
+        };
+
+        kept.push(Box::new(stmt));
+    }
+
+    kept.reverse();
+
+    kept
+}
+
+#[allow(clippy::vec_box)]
+fn map_body<F: FnOnce(Vec<Box<Stmt>>) -> Vec<Box<Stmt>>>(function: Function, f: F) -> Box<Function> {
+    Box::new(Function {
+        body: f(function.body),
+        ..function
+    })
+}
+
+/// Rebuilds condition with every compared expression replaced, left to right
+fn map_exprs<F: FnMut(Expr) -> Box<Expr>>(cond: Cond, f: &mut F) -> Box<Cond> {
+    Box::new(match cond {
+        Cond::Cmp(l_expr, relation, r_expr) => {
+            let l_expr = f(*l_expr);
+            Cond::Cmp(l_expr, relation, f(*r_expr))
+        }
+        Cond::And(l_cond, r_cond) => {
+            let l_cond = map_exprs(*l_cond, f);
+            Cond::And(l_cond, map_exprs(*r_cond, f))
+        }
+        Cond::Or(l_cond, r_cond) => {
+            let l_cond = map_exprs(*l_cond, f);
+            Cond::Or(l_cond, map_exprs(*r_cond, f))
+        }
+        Cond::Not(cond) => Cond::Not(map_exprs(*cond, f)),
+    })
+}
+
+fn collect_cond_vars(cond: &Cond, vars: &mut HashSet<String>) {
+    match cond {
+        Cond::Cmp(l_expr, _, r_expr) => {
+            collect_vars(l_expr, vars);
+            collect_vars(r_expr, vars);
+        }
+        Cond::And(l_cond, r_cond) | Cond::Or(l_cond, r_cond) => {
+            collect_cond_vars(l_cond, vars);
+            collect_cond_vars(r_cond, vars);
+        }
+        Cond::Not(cond) => collect_cond_vars(cond, vars),
+    }
+}
+
+/// Variables assigned anywhere in statements, including nested blocks
+fn collect_assigned(stmts: &[Box<Stmt>], vars: &mut HashSet<String>) {
+    for stmt in stmts.iter() {
+        match stmt.as_ref() {
+            Stmt::SAss(ident, _) => {
+                vars.insert(ident.clone());
+            }
+            Stmt::SExpr(_) | Stmt::SFn(_) | Stmt::SReturn(_, _) => {}
+            Stmt::SIf(_, then, otherwise) => {
+                collect_assigned(then, vars);
+                collect_assigned(otherwise, vars);
+            }
+            Stmt::SWhile(_, body) => collect_assigned(body, vars),
+        }
+    }
+}
+
+fn collect_vars(expr: &Expr, vars: &mut HashSet<String>) {
+    match expr {
+        Expr::Number(_) => {}
+        Expr::Ident(ident, _) => {
+            vars.insert(ident.clone());
+        }
+        Expr::Op(l_expr, _, r_expr) => {
+            collect_vars(l_expr, vars);
+            collect_vars(r_expr, vars);
+        }
+        Expr::Neg(expr) => collect_vars(expr, vars),
+        Expr::Call(_, args, _) => args.iter().for_each(|arg| collect_vars(arg, vars)),
+    }
+}
+
+fn substitute(expr: Expr, known: &HashMap<String, i32>) -> Expr {
+    match expr {
+        Expr::Ident(ident, span) => match known.get(&ident) {
+            Some(n) => Expr::Number(*n),
+            None => Expr::Ident(ident, span),
+        },
+        Expr::Op(l_expr, opcode, r_expr) => Expr::Op(
+            Box::new(substitute(*l_expr, known)),
+            opcode,
+            Box::new(substitute(*r_expr, known)),
+        ),
+        Expr::Neg(expr) => Expr::Neg(Box::new(substitute(*expr, known))),
+        Expr::Call(name, args, span) => {
+            let args = args.into_iter().map(|arg| Box::new(substitute(*arg, known))).collect();
+            Expr::Call(name, args, span)
+        }
+        expr => expr,
+    }
+}
+
+/// Folds operations on constants with the same wrap-around semantics as the backends and applies
+/// algebraic identities (`x*1`, `x+0`, `x*0`, `x-x`, ...). Division by zero is never folded and
+/// subexpressions which could divide by zero are never dropped, so runtime errors are preserved.
+fn fold_expr(expr: Expr) -> Box<Expr> {
+    match expr {
+        Expr::Op(l_expr, opcode, r_expr) => simplify(fold_expr(*l_expr), opcode, fold_expr(*r_expr)),
+        Expr::Neg(expr) => match *fold_expr(*expr) {
+            Expr::Number(n) => Box::new(Expr::Number(n.wrapping_neg())),
+            Expr::Neg(expr) => expr,
+            expr => Box::new(Expr::Neg(Box::new(expr))),
+        },
+        Expr::Call(name, args, span) => {
+            Box::new(Expr::Call(name, args.into_iter().map(|arg| fold_expr(*arg)).collect(), span))
+        }
+        expr => Box::new(expr),
+    }
+}
+
+/// What an operation with already folded operands reduces to
+enum Simplified {
+    Constant(i32),
+    Left,
+    Right,
+    Unchanged,
+}
+
+fn simplify(l_expr: Box<Expr>, opcode: Opcode, r_expr: Box<Expr>) -> Box<Expr> {
+    use Expr::Number;
+    use Opcode::*;
+
+    let simplified = match (l_expr.as_ref(), opcode, r_expr.as_ref()) {
+        (Number(l), _, Number(r)) => match interp::eval_op(opcode, *l, *r) {
+            Ok(n) => Simplified::Constant(n),
+            Err(_) => Simplified::Unchanged,
+        },
+        (_, Add, Number(0)) | (_, Sub, Number(0)) | (_, Mul, Number(1)) | (_, Div, Number(1)) => {
+            Simplified::Left
+        }
+        (Number(0), Add, _) | (Number(1), Mul, _) => Simplified::Right,
+        (_, Mul, Number(0)) if !may_fail(&l_expr) => Simplified::Constant(0),
+        (Number(0), Mul, _) if !may_fail(&r_expr) => Simplified::Constant(0),
+        (l, Sub, r) if same_expr(l, r) && !may_fail(l) => Simplified::Constant(0),
+        _ => Simplified::Unchanged,
+    };
+
+    match simplified {
+        Simplified::Constant(n) => Box::new(Number(n)),
+        Simplified::Left => l_expr,
+        Simplified::Right => r_expr,
+        Simplified::Unchanged => Box::new(Expr::Op(l_expr, opcode, r_expr)),
+    }
+}
+
+/// Whether evaluating expression could end with division by zero or has other effects: a call
+/// may print, fail or never return
+pub fn may_fail(expr: &Expr) -> bool {
+    match expr {
+        Expr::Number(_) | Expr::Ident(_, _) => false,
+        Expr::Op(l_expr, opcode, r_expr) => {
+            let divisor_may_be_zero = opcode.is_division() && match r_expr.as_ref() {
+                Expr::Number(n) => *n == 0,
+                _ => true,
+            };
+
+            divisor_may_be_zero || may_fail(l_expr) || may_fail(r_expr)
+        }
+        Expr::Neg(expr) => may_fail(expr),
+        Expr::Call(_, _, _) => true,
+    }
+}
+
+/// Structural equality ignoring source spans
+pub fn same_expr(a: &Expr, b: &Expr) -> bool {
+    match (a, b) {
+        (Expr::Number(a), Expr::Number(b)) => a == b,
+        (Expr::Ident(a, _), Expr::Ident(b, _)) => a == b,
+        (Expr::Op(a_l, a_opcode, a_r), Expr::Op(b_l, b_opcode, b_r)) => {
+            a_opcode == b_opcode && same_expr(a_l, b_l) && same_expr(a_r, b_r)
+        }
+        (Expr::Neg(a), Expr::Neg(b)) => same_expr(a, b),
+        _ => false,
+    }
+}
@@ -0,0 +1,156 @@
+use std::collections::{HashMap, HashSet};
+use std::ptr;
+
+use crate::ast::{self, Cond, Expr, Function, Span, Stmt};
+use crate::error::{CompileError, ErrorKind};
+
+/// Semantic analysis shared by all backends.
+/// Reports every use of a variable before its first assignment, in source order. After `if`
+/// only variables assigned in both branches (or before it) count as assigned, ignoring a branch which
+/// always returns; `while` body may not run at all, so assignments inside it don't count after the loop.
+/// Function bodies are checked where they're declared with only their parameters assigned; calls
+/// must name a function declared anywhere in the program and pass one argument per parameter.
+pub fn check(stmts: &[Box<Stmt>]) -> Result<(), Vec<CompileError>> {
+    let mut checker = Checker {
+        functions: HashMap::new(),
+        errors: vec![],
+    };
+
+    for function in ast::functions(stmts) {
+        checker.functions.entry(function.name.as_str()).or_insert(function);
+    }
+
+    checker.check_stmts(stmts, &mut HashSet::new(), false);
+
+    if checker.errors.is_empty() {
+        Ok(())
+    } else {
+        Err(checker.errors)
+    }
+}
+
+/// Whether every path through statements ends with `return`. Loop condition may be false right
+/// away, so `while` never counts.
+pub fn returns(stmts: &[Box<Stmt>]) -> bool {
+    stmts.iter().any(|stmt| match stmt.as_ref() {
+        Stmt::SReturn(_, _) => true,
+        Stmt::SIf(_, then, otherwise) => returns(then) && returns(otherwise),
+        _ => false,
+    })
+}
+
+struct Checker<'a> {
+    functions: HashMap<&'a str, &'a Function>, // first declaration of each name
+    errors: Vec<CompileError>,
+}
+
+impl<'a> Checker<'a> {
+    fn error(&mut self, kind: ErrorKind, span: Span) {
+        self.errors.push(CompileError::new(kind, span));
+    }
+
+    fn check_function(&mut self, function: &'a Function) {
+        if !ptr::eq(self.functions[function.name.as_str()], function) {
+            self.error(ErrorKind::DuplicateFunction(function.name.clone()), function.span);
+        }
+
+        let mut declared = HashSet::new();
+        for (param, span) in function.params.iter().zip(function.param_spans.iter()) {
+            if !declared.insert(param.as_str()) {
+                self.error(ErrorKind::DuplicateParameter(param.clone()), *span);
+            }
+        }
+
+        self.check_stmts(&function.body, &mut declared, true);
+
+        if !returns(&function.body) {
+            self.error(ErrorKind::MissingReturn(function.name.clone()), function.span);
+        }
+    }
+
+    fn check_stmts(
+        &mut self,
+        stmts: &'a [Box<Stmt>],
+        declared: &mut HashSet<&'a str>,
+        in_function: bool,
+    ) {
+        for stmt in stmts.iter() {
+            match stmt.as_ref() {
+                Stmt::SAss(ident, expr) => {
+                    self.check_expr(expr, declared);
+                    declared.insert(ident.as_str());
+                }
+                Stmt::SExpr(expr) => self.check_expr(expr, declared),
+                Stmt::SIf(cond, then, otherwise) => {
+                    self.check_cond(cond, declared);
+
+                    let mut declared_then = declared.clone();
+                    self.check_stmts(then, &mut declared_then, in_function);
+                    self.check_stmts(otherwise, declared, in_function);
+
+                    match (returns(then), returns(otherwise)) {
+                        (false, true) => *declared = declared_then,
+                        (false, false) => declared.retain(|ident| declared_then.contains(ident)),
+                        _ => {}
+                    }
+                }
+                Stmt::SWhile(cond, body) => {
+                    self.check_cond(cond, declared);
+                    self.check_stmts(body, &mut declared.clone(), in_function);
+                }
+                Stmt::SFn(function) => self.check_function(function),
+                Stmt::SReturn(expr, span) => {
+                    self.check_expr(expr, declared);
+                    if !in_function {
+                        self.error(ErrorKind::ReturnOutsideFunction, *span);
+                    }
+                }
+            }
+        }
+    }
+
+    fn check_cond(&mut self, cond: &Cond, declared: &HashSet<&str>) {
+        match cond {
+            Cond::Cmp(l_expr, _, r_expr) => {
+                self.check_expr(l_expr, declared);
+                self.check_expr(r_expr, declared);
+            }
+            Cond::And(l_cond, r_cond) | Cond::Or(l_cond, r_cond) => {
+                self.check_cond(l_cond, declared);
+                self.check_cond(r_cond, declared);
+            }
+            Cond::Not(cond) => self.check_cond(cond, declared),
+        }
+    }
+
+    fn check_expr(&mut self, expr: &Expr, declared: &HashSet<&str>) {
+        match expr {
+            Expr::Number(_) => {}
+            Expr::Ident(ident, span) => {
+                if !declared.contains(ident.as_str()) {
+                    self.error(ErrorKind::UndeclaredVariable(ident.clone()), *span);
+                }
+            }
+            Expr::Op(l_expr, _, r_expr) => {
+                self.check_expr(l_expr, declared);
+                self.check_expr(r_expr, declared);
+            }
+            Expr::Neg(expr) => self.check_expr(expr, declared),
+            Expr::Call(name, args, span) => {
+                let params = self.functions.get(name.as_str()).map(|function| function.params.len());
+                match params {
+                    None => self.error(ErrorKind::UndefinedFunction(name.clone()), *span),
+                    Some(params) if params != args.len() => {
+                        let kind = ErrorKind::ArgumentCount(name.clone(), params, args.len());
+                        self.error(kind, *span);
+                    }
+                    Some(_) => {}
+                }
+
+                for arg in args.iter() {
+                    self.check_expr(arg, declared);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,580 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::ast::{self, Cond, Expr, Function, Opcode, Relation, Stmt};
+
+/// Compiles program to WebAssembly text format. Module imports `env.printInt` and exports `main`;
+/// each function becomes a module function taking and returning `i32`.
+/// Like the other backends, it relies on `semantic::check` having accepted the program.
+pub fn compile_wat(stmts: &[Box<Stmt>]) -> String {
+    compile_module(stmts).generate_text()
+}
+
+/// Same as `compile_wat`, but produces binary WebAssembly module.
+pub fn compile_wasm(stmts: &[Box<Stmt>]) -> Vec<u8> {
+    compile_module(stmts).generate_binary()
+}
+
+fn compile_module(stmts: &[Box<Stmt>]) -> Module {
+    let mut names = vec![String::from("printInt"), String::from("main")];
+    let mut indices = HashMap::new();
+    for (i, function) in ast::functions(stmts).enumerate() {
+        names.push(format!("fn.{}", function.name));
+        indices
+            .entry(function.name.as_str())
+            .or_insert(FIRST_FUNCTION_INDEX + i as u32);
+    }
+
+    let mut functions = vec![];
+    for function in ast::functions(stmts) {
+        let mut state = WasmState::new(&function.params, &indices);
+        compile_function(function, &mut state);
+        functions.push(state.finish());
+    }
+
+    let mut state = WasmState::new(&[], &indices);
+    compile_stmts(stmts, &mut state);
+
+    Module {
+        names,
+        main: state.finish(),
+        functions,
+    }
+}
+
+// Index 0 is taken by imported printInt, functions follow main
+const PRINT_INT_INDEX: u32 = 0;
+const MAIN_INDEX: u32 = 1;
+const FIRST_FUNCTION_INDEX: u32 = 2;
+
+enum Instruction {
+    I32Const(i32),
+    LocalGet(u32),
+    LocalSet(u32),
+    Arithmetic(Opcode),
+    Compare(Relation),
+    I32Eqz,
+    If(bool), // whether the block leaves i32 on the stack
+    Else,
+    Block,
+    Loop,
+    Br(u32), // label index counts enclosing blocks outwards from 0
+    BrIf(u32),
+    End,
+    Call(u32),
+    Return,
+    Unreachable,
+}
+
+impl Instruction {
+    fn encode(&self, out: &mut Vec<u8>) {
+        use Instruction::*;
+
+        match self {
+            I32Const(n) => {
+                out.push(0x41);
+                put_sleb128(out, *n);
+            }
+            LocalGet(index) => {
+                out.push(0x20);
+                put_uleb128(out, *index);
+            }
+            LocalSet(index) => {
+                out.push(0x21);
+                put_uleb128(out, *index);
+            }
+            Arithmetic(opcode) => out.push(match opcode {
+                Opcode::Add => 0x6a,
+                Opcode::Sub => 0x6b,
+                Opcode::Mul => 0x6c,
+                Opcode::Div => 0x6d,
+                Opcode::Rem => 0x6f,
+                Opcode::And => 0x71,
+                Opcode::Or => 0x72,
+                Opcode::Xor => 0x73,
+                Opcode::Shl => 0x74,
+                Opcode::Shr => 0x75,
+            }),
+            Compare(relation) => out.push(match relation {
+                Relation::Eq => 0x46,
+                Relation::Ne => 0x47,
+                Relation::Lt => 0x48,
+                Relation::Gt => 0x4a,
+                Relation::Le => 0x4c,
+                Relation::Ge => 0x4e,
+            }),
+            I32Eqz => out.push(0x45),
+            If(result) => out.extend_from_slice(&[0x04, if *result { 0x7f } else { 0x40 }]),
+            Else => out.push(0x05),
+            Block => out.extend_from_slice(&[0x02, 0x40]),
+            Loop => out.extend_from_slice(&[0x03, 0x40]),
+            Br(depth) => {
+                out.push(0x0c);
+                put_uleb128(out, *depth);
+            }
+            BrIf(depth) => {
+                out.push(0x0d);
+                put_uleb128(out, *depth);
+            }
+            End => out.push(0x0b),
+            Call(index) => {
+                out.push(0x10);
+                put_uleb128(out, *index);
+            }
+            Return => out.push(0x0f),
+            Unreachable => out.push(0x00),
+        }
+    }
+}
+
+/// Compiled function: its locals, parameters first, and body
+struct Body {
+    instructions: Vec<Instruction>,
+    var_names: Vec<String>,
+    arity: usize,
+}
+
+struct Module {
+    names: Vec<String>, // of every function, by index
+    main: Body,
+    functions: Vec<Body>,
+}
+
+impl Module {
+    fn format_instruction(&self, body: &Body, instruction: &Instruction) -> String {
+        use Instruction::*;
+
+        match instruction {
+            I32Const(n) => format!("i32.const {}", n),
+            LocalGet(index) => format!("local.get ${}", body.var_names[*index as usize]),
+            LocalSet(index) => format!("local.set ${}", body.var_names[*index as usize]),
+            Arithmetic(opcode) => format!("i32.{}", WasmOpcode(opcode)),
+            Compare(relation) => format!("i32.{}", WasmRelation(relation)),
+            I32Eqz => String::from("i32.eqz"),
+            If(true) => String::from("if (result i32)"),
+            If(false) => String::from("if"),
+            Else => String::from("else"),
+            Block => String::from("block"),
+            Loop => String::from("loop"),
+            Br(depth) => format!("br {}", depth),
+            BrIf(depth) => format!("br_if {}", depth),
+            End => String::from("end"),
+            Call(index) => format!("call ${}", self.names[*index as usize]),
+            Return => String::from("return"),
+            Unreachable => String::from("unreachable"),
+        }
+    }
+
+    fn generate_text(&self) -> String {
+        let functions: String = self
+            .functions
+            .iter()
+            .enumerate()
+            .map(|(i, body)| {
+                let params: String = body.var_names[..body.arity]
+                    .iter()
+                    .map(|name| format!(" (param ${} i32)", name))
+                    .collect();
+                let name = &self.names[FIRST_FUNCTION_INDEX as usize + i];
+                let header = format!("\t(func ${}{} (result i32)", name, params);
+
+                self.generate_function(&header, body)
+            })
+            .collect();
+
+        format!(
+            "{}{}{}{}{}",
+            "(module\n",
+            "\t(import \"env\" \"printInt\" (func $printInt (param i32)))\n",
+            self.generate_function("\t(func $main (export \"main\")", &self.main),
+            functions,
+            ")\n"
+        )
+    }
+
+    fn generate_function(&self, header: &str, body: &Body) -> String {
+        let locals: String = body.var_names[body.arity..]
+            .iter()
+            .map(|name| format!("\n\t\t(local ${} i32)", name))
+            .collect();
+        // Instructions inside `if`, `block` and `loop` are indented one level deeper
+        let mut depth = 0;
+        let instructions: String = body
+            .instructions
+            .iter()
+            .map(|instruction| {
+                let indent = match instruction {
+                    Instruction::Else | Instruction::End => depth - 1,
+                    _ => depth,
+                };
+                match instruction {
+                    Instruction::If(_) | Instruction::Block | Instruction::Loop => depth += 1,
+                    Instruction::End => depth -= 1,
+                    _ => {}
+                }
+
+                format!("\n\t\t{}{}", "\t".repeat(indent), self.format_instruction(body, instruction))
+            })
+            .collect();
+
+        format!("{}{}{}\n\t)\n", header, locals, instructions)
+    }
+
+    fn generate_binary(&self) -> Vec<u8> {
+        let mut out = vec![0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00];
+
+        // Type section: 0 = (i32) -> (), 1 = () -> (), then (i32, ...) -> i32 for each function
+        let mut types = vec![];
+        put_uleb128(&mut types, 2 + self.functions.len() as u32);
+        types.extend_from_slice(&[0x60, 0x01, 0x7f, 0x00, 0x60, 0x00, 0x00]);
+        for body in self.functions.iter() {
+            types.push(0x60);
+            put_uleb128(&mut types, body.arity as u32);
+            types.extend(vec![0x7f; body.arity]);
+            types.extend_from_slice(&[0x01, 0x7f]);
+        }
+        put_section(&mut out, 1, &types);
+
+        let mut imports = vec![0x01];
+        put_name(&mut imports, "env");
+        put_name(&mut imports, "printInt");
+        imports.extend_from_slice(&[0x00, 0x00]); // function of type 0
+        put_section(&mut out, 2, &imports);
+
+        // main has type 1, function i has type 2 + i
+        let mut function_types = vec![];
+        put_uleb128(&mut function_types, 1 + self.functions.len() as u32);
+        for type_index in 1..2 + self.functions.len() as u32 {
+            put_uleb128(&mut function_types, type_index);
+        }
+        put_section(&mut out, 3, &function_types);
+
+        let mut exports = vec![0x01];
+        put_name(&mut exports, "main");
+        exports.push(0x00); // function
+        put_uleb128(&mut exports, MAIN_INDEX);
+        put_section(&mut out, 7, &exports);
+
+        let mut code = vec![];
+        put_uleb128(&mut code, 1 + self.functions.len() as u32);
+        for body in std::iter::once(&self.main).chain(self.functions.iter()) {
+            let encoded = body.encode();
+            put_uleb128(&mut code, encoded.len() as u32);
+            code.extend_from_slice(&encoded);
+        }
+        put_section(&mut out, 10, &code);
+
+        out
+    }
+}
+
+impl Body {
+    /// Locals other than parameters, then instructions
+    fn encode(&self) -> Vec<u8> {
+        let mut out = vec![];
+        let locals = self.var_names.len() - self.arity;
+        if locals == 0 {
+            out.push(0x00);
+        } else {
+            out.push(0x01);
+            put_uleb128(&mut out, locals as u32);
+            out.push(0x7f); // i32
+        }
+        self.instructions
+            .iter()
+            .for_each(|instruction| instruction.encode(&mut out));
+        out.push(0x0b); // end
+
+        out
+    }
+}
+
+struct WasmState<'a> {
+    instructions: Vec<Instruction>,
+    var_index_map: HashMap<String, u32>,
+    var_names: Vec<String>,
+    arity: usize,
+    function_indices: &'a HashMap<&'a str, u32>,
+}
+
+impl<'a> WasmState<'a> {
+    /// Parameters of function are the first locals; `main` has none
+    fn new(params: &[String], function_indices: &'a HashMap<&'a str, u32>) -> WasmState<'a> {
+        WasmState {
+            instructions: vec![],
+            var_index_map: params
+                .iter()
+                .enumerate()
+                .map(|(i, param)| (param.clone(), i as u32))
+                .collect(),
+            var_names: params.to_vec(),
+            arity: params.len(),
+            function_indices,
+        }
+    }
+
+    fn finish(self) -> Body {
+        Body {
+            instructions: self.instructions,
+            var_names: self.var_names,
+            arity: self.arity,
+        }
+    }
+
+    /// Variables are assigned before they're read, so their locals already exist
+    fn push_load(&mut self, ident: &str) {
+        self.instructions.push(Instruction::LocalGet(self.var_index_map[ident]));
+    }
+
+    fn push_store(&mut self, ident: &str) {
+        let index = self.local_index(ident);
+
+        self.instructions.push(Instruction::LocalSet(index));
+    }
+
+    /// Index of local, added on first use
+    fn local_index(&mut self, name: &str) -> u32 {
+        match self.var_index_map.get(name) {
+            Some(index) => *index,
+            None => {
+                let index = self.var_names.len() as u32;
+                self.var_names.push(name.to_string());
+                self.var_index_map.insert(name.to_string(), index);
+                index
+            }
+        }
+    }
+
+    /// `i32.div_s` traps on `i32::MIN / -1`, so division by -1 negates instead, as in the interpreter.
+    /// Operands are kept in locals named so that they can't clash with variables.
+    fn push_division(&mut self) {
+        use Instruction::*;
+
+        let divisor = self.local_index("div.divisor");
+        let dividend = self.local_index("div.dividend");
+
+        self.instructions.extend(vec![
+            LocalSet(divisor),
+            LocalSet(dividend),
+            LocalGet(divisor),
+            I32Const(-1),
+            Compare(Relation::Eq),
+            If(true),
+            I32Const(0),
+            LocalGet(dividend),
+            Arithmetic(Opcode::Sub),
+            Else,
+            LocalGet(dividend),
+            LocalGet(divisor),
+            Arithmetic(Opcode::Div),
+            End,
+        ]);
+    }
+
+    fn push_call(&mut self, name: &str) {
+        let index = self.function_indices[name];
+        self.instructions.push(Instruction::Call(index));
+    }
+}
+
+struct WasmOpcode<'a>(&'a Opcode);
+
+impl fmt::Display for WasmOpcode<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use Opcode::*;
+        match self.0 {
+            Add => write!(f, "add"),
+            Sub => write!(f, "sub"),
+            Mul => write!(f, "mul"),
+            Div => write!(f, "div_s"),
+            Rem => write!(f, "rem_s"),
+            And => write!(f, "and"),
+            Or => write!(f, "or"),
+            Xor => write!(f, "xor"),
+            Shl => write!(f, "shl"),
+            Shr => write!(f, "shr_s"),
+        }
+    }
+}
+
+struct WasmRelation<'a>(&'a Relation);
+
+impl fmt::Display for WasmRelation<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use Relation::*;
+        match self.0 {
+            Lt => write!(f, "lt_s"),
+            Le => write!(f, "le_s"),
+            Gt => write!(f, "gt_s"),
+            Ge => write!(f, "ge_s"),
+            Eq => write!(f, "eq"),
+            Ne => write!(f, "ne"),
+        }
+    }
+}
+
+/// Validation needs `i32` on the stack at the end of function, which only `return` leaves, so a body
+/// not ending with one (e.g. ending with `if` returning in both branches) ends with `unreachable`.
+fn compile_function(function: &Function, state: &mut WasmState) {
+    compile_stmts(&function.body, state);
+
+    if let Some(Stmt::SReturn(_, _)) = function.body.last().map(|stmt| stmt.as_ref()) {
+        return;
+    }
+    state.instructions.push(Instruction::Unreachable);
+}
+
+fn compile_stmts(stmts: &[Box<Stmt>], state: &mut WasmState) {
+    for stmt in stmts.iter() {
+        compile_stmt(stmt, state);
+    }
+}
+
+fn compile_stmt(stmt: &Stmt, state: &mut WasmState) {
+    match stmt {
+        Stmt::SAss(ident, expr) => {
+            compile_expr(expr, state);
+            state.push_store(ident);
+        }
+        Stmt::SExpr(expr) => {
+            compile_expr(expr, state);
+            state.instructions.push(Instruction::Call(PRINT_INT_INDEX));
+        }
+        Stmt::SIf(cond, then, otherwise) => {
+            compile_cond(cond, state);
+            state.instructions.push(Instruction::If(false));
+
+            compile_stmts(then, state);
+
+            if !otherwise.is_empty() {
+                state.instructions.push(Instruction::Else);
+                compile_stmts(otherwise, state);
+            }
+
+            state.instructions.push(Instruction::End);
+        }
+        // Branch to `block` leaves the loop, branch to `loop` starts the next iteration
+        Stmt::SWhile(cond, body) => {
+            state.instructions.push(Instruction::Block);
+            state.instructions.push(Instruction::Loop);
+            compile_cond(cond, state);
+            state.instructions.push(Instruction::I32Eqz);
+            state.instructions.push(Instruction::BrIf(1));
+
+            compile_stmts(body, state);
+
+            state.instructions.push(Instruction::Br(0));
+            state.instructions.push(Instruction::End);
+            state.instructions.push(Instruction::End);
+        }
+        Stmt::SFn(_) => {}
+        Stmt::SReturn(expr, _) => {
+            compile_expr(expr, state);
+            state.instructions.push(Instruction::Return);
+        }
+    }
+}
+
+/// Leaves 1 on the stack when condition holds and 0 otherwise. Structured control flow has no jumps
+/// into the middle of a condition, so `&&` and `||` evaluate their right operand inside `if`.
+fn compile_cond(cond: &Cond, state: &mut WasmState) {
+    match cond {
+        Cond::Cmp(l_expr, relation, r_expr) => {
+            compile_expr(l_expr, state);
+            compile_expr(r_expr, state);
+            state.instructions.push(Instruction::Compare(*relation));
+        }
+        Cond::And(l_cond, r_cond) => {
+            compile_cond(l_cond, state);
+            state.instructions.push(Instruction::If(true));
+            compile_cond(r_cond, state);
+            state.instructions.push(Instruction::Else);
+            state.instructions.push(Instruction::I32Const(0));
+            state.instructions.push(Instruction::End);
+        }
+        Cond::Or(l_cond, r_cond) => {
+            compile_cond(l_cond, state);
+            state.instructions.push(Instruction::If(true));
+            state.instructions.push(Instruction::I32Const(1));
+            state.instructions.push(Instruction::Else);
+            compile_cond(r_cond, state);
+            state.instructions.push(Instruction::End);
+        }
+        Cond::Not(cond) => {
+            compile_cond(cond, state);
+            state.instructions.push(Instruction::I32Eqz);
+        }
+    }
+}
+
+/// Post-order traversal, as in jvm.rs. Wasm has no swap, so operands are always pushed left to right,
+/// which is also the order calls have to happen in.
+fn compile_expr(expr: &Expr, state: &mut WasmState) {
+    match expr {
+        Expr::Number(n) => state.instructions.push(Instruction::I32Const(*n)),
+        Expr::Ident(ident, _) => state.push_load(ident),
+        Expr::Op(l_expr, opcode, r_expr) => {
+            compile_expr(l_expr, state);
+            compile_expr(r_expr, state);
+            match (opcode, r_expr.as_ref()) {
+                (Opcode::Div, Expr::Number(n)) if *n != -1 => {
+                    state.instructions.push(Instruction::Arithmetic(Opcode::Div))
+                }
+                (Opcode::Div, _) => state.push_division(),
+                _ => state.instructions.push(Instruction::Arithmetic(*opcode)),
+            }
+        }
+        // No i32.neg in WebAssembly
+        Expr::Neg(expr) => {
+            state.instructions.push(Instruction::I32Const(0));
+            compile_expr(expr, state);
+            state.instructions.push(Instruction::Arithmetic(Opcode::Sub));
+        }
+        Expr::Call(name, args, _) => {
+            for arg in args.iter() {
+                compile_expr(arg, state);
+            }
+            state.push_call(name);
+        }
+    }
+}
+
+fn put_section(out: &mut Vec<u8>, id: u8, contents: &[u8]) {
+    out.push(id);
+    put_uleb128(out, contents.len() as u32);
+    out.extend_from_slice(contents);
+}
+
+fn put_name(out: &mut Vec<u8>, name: &str) {
+    put_uleb128(out, name.len() as u32);
+    out.extend_from_slice(name.as_bytes());
+}
+
+fn put_uleb128(out: &mut Vec<u8>, mut value: u32) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+
+        if value == 0 {
+            out.push(byte);
+            return;
+        }
+
+        out.push(byte | 0x80);
+    }
+}
+
+fn put_sleb128(out: &mut Vec<u8>, mut value: i32) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+
+        let sign_bit_clear = byte & 0x40 == 0;
+        if (value == 0 && sign_bit_clear) || (value == -1 && !sign_bit_clear) {
+            out.push(byte);
+            return;
+        }
+
+        out.push(byte | 0x80);
+    }
+}
@@ -0,0 +1,702 @@
+use std::cmp;
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::ast::{self, Cond, Expr, Function, Opcode, Relation, Stmt};
+
+/// Compiles program to x86-64 assembly (GAS, AT&T syntax) to be linked against libc with e.g. `gcc`.
+/// Variables live in stack frame, expressions are evaluated in registers ordered by Sethi-Ullman
+/// numbering (same idea as stack limit in jvm.rs), spilling onto machine stack only when registers run out.
+///
+/// Functions follow `main`, labelled `.LfnN` by declaration order. Caller saves the registers it
+/// still needs and pushes arguments left to right; callee copies them into its own frame and
+/// returns its value in %eax.
+///
+/// Program has to pass `semantic::check` first (as from `parse_and_check`), so every variable is
+/// assigned before it's read, calls match their functions and function bodies end with `return`.
+pub fn compile(stmts: &[Box<Stmt>]) -> String {
+    let mut labels = HashMap::new();
+    for (i, function) in ast::functions(stmts).enumerate() {
+        labels.entry(function.name.as_str()).or_insert(i);
+    }
+
+    let mut functions = String::new();
+    let mut label_count = 0;
+    for (i, function) in ast::functions(stmts).enumerate() {
+        let mut state = X86State::new(&labels, label_count);
+
+        compile_function(function, &mut state);
+
+        label_count = state.label_count;
+        functions += &state.generate_function(&format!(".Lfn{}", i));
+    }
+
+    let mut state = X86State::new(&labels, label_count);
+
+    let tagged_stmts = tag_stmts(stmts);
+
+    compile_tagged_stmts(&tagged_stmts, &mut state);
+
+    state.generate_code(&functions)
+}
+
+fn compile_function(function: &Function, state: &mut X86State) {
+    // Arguments were pushed in order, so the last one is right above the return address
+    for (i, param) in function.params.iter().enumerate() {
+        let offset = 16 + 8 * (function.params.len() - 1 - i);
+        state.instructions.push(format!("movl {}(%rbp), {}", offset, Register::R11));
+        state.store(Register::R11, param);
+    }
+
+    compile_tagged_stmts(&tag_stmts(&function.body), state);
+}
+
+/// Registers used for evaluating expressions. %eax and %edx are left out as they're needed by idiv
+/// (and %eax saves %ecx while its low byte holds shift amount), %r11 is kept as scratch for spilled values.
+const REGISTERS: [Register; 6] = [
+    Register::Ecx,
+    Register::Esi,
+    Register::Edi,
+    Register::R8,
+    Register::R9,
+    Register::R10,
+];
+
+#[derive(Clone, Copy, PartialEq)]
+enum Register {
+    Eax,
+    Ecx,
+    Edx,
+    Esi,
+    Edi,
+    R8,
+    R9,
+    R10,
+    R11,
+}
+
+impl Register {
+    fn name64(self) -> &'static str {
+        use Register::*;
+        match self {
+            Eax => "%rax",
+            Ecx => "%rcx",
+            Edx => "%rdx",
+            Esi => "%rsi",
+            Edi => "%rdi",
+            R8 => "%r8",
+            R9 => "%r9",
+            R10 => "%r10",
+            R11 => "%r11",
+        }
+    }
+}
+
+impl fmt::Display for Register {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use Register::*;
+        let name = match self {
+            Eax => "%eax",
+            Ecx => "%ecx",
+            Edx => "%edx",
+            Esi => "%esi",
+            Edi => "%edi",
+            R8 => "%r8d",
+            R9 => "%r9d",
+            R10 => "%r10d",
+            R11 => "%r11d",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Source operand of an instruction: leaves of expression tree don't need to be loaded into register first.
+#[derive(Clone, Copy)]
+enum Operand {
+    Immediate(i32),
+    Local(usize),
+    Register(Register),
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Operand::Immediate(n) => write!(f, "${}", n),
+            Operand::Local(slot) => write!(f, "-{}(%rbp)", 4 * (slot + 1)),
+            Operand::Register(register) => write!(f, "{}", register),
+        }
+    }
+}
+
+struct X86State<'a> {
+    instructions: Vec<String>,
+    var_slot_map: HashMap<String, usize>,
+    label_count: usize,
+    stack_depth: usize, // bytes pushed below the frame, to align calls
+    function_labels: &'a HashMap<&'a str, usize>,
+}
+
+impl<'a> X86State<'a> {
+    /// Labels continue from `label_count`, as they're shared by the whole file
+    fn new(function_labels: &'a HashMap<&'a str, usize>, label_count: usize) -> X86State<'a> {
+        X86State {
+            instructions: vec![],
+            var_slot_map: HashMap::new(),
+            label_count,
+            stack_depth: 0,
+            function_labels,
+        }
+    }
+
+    fn generate_code(&self, functions: &str) -> String {
+        format!(
+            "{}{}{}{}{}{}",
+            ".section .rodata\n.Lformat:\n\t.string \"%d\\n\"\n",
+            // Flushed after every line, so output isn't lost when the program dies on division by zero
+            ".text\nprintInt:\n\tpushq %rbp\n\tmovq %rsp, %rbp\n\tmovl %edi, %esi\n\tleaq .Lformat(%rip), %rdi\n\txorl %eax, %eax\n\tcall printf@PLT\n\txorl %edi, %edi\n\tcall fflush@PLT\n\tpopq %rbp\n\tret\n",
+            ".globl main\n",
+            self.generate_function("main"),
+            "\txorl %eax, %eax\n\tleave\n\tret\n",
+            format_args!("{}.section .note.GNU-stack,\"\",@progbits\n", functions)
+        )
+    }
+
+    /// Prologue and body; functions end with their own `ret`, main's epilogue is added by generate_code
+    fn generate_function(&self, label: &str) -> String {
+        // Labels go in the first column
+        let instructions: String = self
+            .instructions
+            .iter()
+            .map(|instruction| match instruction.ends_with(':') {
+                true => format!("{}\n", instruction),
+                false => format!("\t{}\n", instruction),
+            })
+            .collect();
+        // Keep %rsp 16-byte aligned for calls into libc
+        let frame_size = (4 * self.var_slot_map.len() + 15) & !15;
+
+        format!(
+            "{}:\n\tpushq %rbp\n\tmovq %rsp, %rbp\n\tsubq ${}, %rsp\n{}",
+            label, frame_size, instructions
+        )
+    }
+
+    /// Variables are assigned before they're read, so their slots are already taken
+    fn get_var_operand(&self, ident: &str) -> Operand {
+        Operand::Local(self.var_slot_map[ident])
+    }
+
+    fn new_label(&mut self) -> usize {
+        self.label_count += 1;
+        self.label_count - 1
+    }
+
+    fn label(&mut self, label: usize) {
+        self.instructions.push(format!(".L{}:", label));
+    }
+
+    fn jump(&mut self, label: usize) {
+        self.instructions.push(format!("jmp .L{}", label));
+    }
+
+    /// Compares `destination` with `source` and jumps when `relation` holds between them
+    fn compare_and_jump(&mut self, relation: Relation, source: &Operand, destination: Register, label: usize) {
+        let condition = match relation {
+            Relation::Lt => "l",
+            Relation::Le => "le",
+            Relation::Gt => "g",
+            Relation::Ge => "ge",
+            Relation::Eq => "e",
+            Relation::Ne => "ne",
+        };
+
+        self.instructions.push(format!("cmpl {}, {}", source, destination));
+        self.instructions.push(format!("j{} .L{}", condition, label));
+    }
+
+    fn mov(&mut self, source: &Operand, destination: Register) {
+        self.instructions
+            .push(format!("movl {}, {}", source, destination));
+    }
+
+    fn store(&mut self, source: Register, ident: &str) {
+        let next_slot = self.var_slot_map.len();
+        let slot = *self
+            .var_slot_map
+            .entry(ident.to_string())
+            .or_insert(next_slot);
+
+        self.instructions
+            .push(format!("movl {}, {}", source, Operand::Local(slot)));
+    }
+
+    fn print(&mut self, source: Register) {
+        self.mov(&Operand::Register(source), Register::Edi);
+        self.instructions.push(String::from("call printInt"));
+    }
+
+    fn push(&mut self, register: Register) {
+        self.stack_depth += 8;
+        self.instructions
+            .push(format!("pushq {}", register.name64()));
+    }
+
+    fn pop(&mut self, register: Register) {
+        self.stack_depth -= 8;
+        self.instructions
+            .push(format!("popq {}", register.name64()));
+    }
+
+    /// Moves %rsp without pushing anything, by a multiple of 8
+    fn reserve(&mut self, bytes: isize) {
+        if bytes > 0 {
+            self.instructions.push(format!("subq ${}, %rsp", bytes));
+        } else if bytes < 0 {
+            self.instructions.push(format!("addq ${}, %rsp", -bytes));
+        }
+        self.stack_depth = (self.stack_depth as isize + bytes) as usize;
+    }
+
+    fn ret(&mut self, source: Register) {
+        self.mov(&Operand::Register(source), Register::Eax);
+        self.instructions.push(String::from("leave"));
+        self.instructions.push(String::from("ret"));
+    }
+
+    fn neg(&mut self, register: Register) {
+        self.instructions.push(format!("negl {}", register));
+    }
+
+    /// destination = destination `opcode` source
+    fn arithmetic(&mut self, opcode: &Opcode, source: &Operand, destination: Register) {
+        use Opcode::*;
+
+        match opcode {
+            Add => self.instructions.push(format!("addl {}, {}", source, destination)),
+            Sub => self.instructions.push(format!("subl {}, {}", source, destination)),
+            Mul => self.instructions.push(format!("imull {}, {}", source, destination)),
+            And => self.instructions.push(format!("andl {}, {}", source, destination)),
+            Or => self.instructions.push(format!("orl {}, {}", source, destination)),
+            Xor => self.instructions.push(format!("xorl {}, {}", source, destination)),
+            Shl | Shr => self.shift(opcode, source, destination),
+            Div | Rem => self.division(opcode, source, destination),
+        }
+    }
+
+    /// idiv faults on `i32::MIN / -1`, so division by -1 negates instead and remainder is 0,
+    /// as in the interpreter and on the JVM
+    fn division(&mut self, opcode: &Opcode, source: &Operand, destination: Register) {
+        let by_minus_one = |state: &mut X86State| match opcode {
+            Opcode::Div => state.neg(destination),
+            _ => state.mov(&Operand::Immediate(0), destination),
+        };
+
+        // idiv has no immediate form
+        let divisor = match source {
+            Operand::Immediate(-1) => return by_minus_one(self),
+            Operand::Immediate(_) => {
+                self.mov(source, Register::R11);
+                Operand::Register(Register::R11)
+            }
+            _ => *source,
+        };
+
+        let divide_label = self.new_label();
+        let end_label = self.new_label();
+
+        self.instructions.push(format!("cmpl $-1, {}", divisor));
+        self.instructions.push(format!("jne .L{}", divide_label));
+        by_minus_one(self);
+        self.jump(end_label);
+
+        self.label(divide_label);
+        self.mov(&Operand::Register(destination), Register::Eax);
+        self.instructions.push(String::from("cltd"));
+        self.instructions.push(format!("idivl {}", divisor));
+
+        let result = if *opcode == Opcode::Div { Register::Eax } else { Register::Edx };
+        self.mov(&Operand::Register(result), destination);
+        self.label(end_label);
+    }
+
+    /// Shift amount other than immediate has to be in %cl, so %ecx is saved in %eax meanwhile.
+    /// Hardware takes the amount modulo 32, as Instant does.
+    fn shift(&mut self, opcode: &Opcode, source: &Operand, destination: Register) {
+        let mnemonic = if *opcode == Opcode::Shl { "sall" } else { "sarl" };
+
+        match source {
+            Operand::Immediate(n) => {
+                self.instructions.push(format!("{} ${}, {}", mnemonic, n & 31, destination))
+            }
+            _ if destination == Register::Ecx => {
+                self.mov(&Operand::Register(Register::Ecx), Register::Eax);
+                self.mov(source, Register::Ecx);
+                self.instructions.push(format!("{} %cl, {}", mnemonic, Register::Eax));
+                self.mov(&Operand::Register(Register::Eax), Register::Ecx);
+            }
+            _ => {
+                self.mov(source, Register::Eax);
+                self.instructions.push(format!("xchgl {}, {}", Register::Eax, Register::Ecx));
+                self.instructions.push(format!("{} %cl, {}", mnemonic, destination));
+                self.mov(&Operand::Register(Register::Eax), Register::Ecx);
+            }
+        }
+    }
+}
+
+/// Wrapper for ast::Expr keeping number of registers needed to compute expression without spilling
+enum TaggedExpr<'a> {
+    Number(i32),
+    Ident(&'a String),
+    Op(Box<TaggedExpr<'a>>, &'a Opcode, Box<TaggedExpr<'a>>, usize), // usize keeps register need
+    Neg(Box<TaggedExpr<'a>>), // negated in place, needs as many registers as its operand
+    Call(&'a String, Vec<TaggedExpr<'a>>), // registers in use are saved around the call
+}
+
+impl TaggedExpr<'_> {
+    fn get_register_need(&self) -> usize {
+        use TaggedExpr::*;
+
+        match *self {
+            Number(_) | Ident(_) | Call(_, _) => 1,
+            Op(_, _, _, need) => need,
+            Neg(ref tagged_expr) => tagged_expr.get_register_need(),
+        }
+    }
+
+    fn contains_call(&self) -> bool {
+        use TaggedExpr::*;
+
+        match self {
+            Number(_) | Ident(_) => false,
+            Op(l_expr, _, r_expr, _) => l_expr.contains_call() || r_expr.contains_call(),
+            Neg(tagged_expr) => tagged_expr.contains_call(),
+            Call(_, _) => true,
+        }
+    }
+
+    /// Same as `opt::may_fail`
+    fn may_fail(&self) -> bool {
+        use TaggedExpr::*;
+
+        match self {
+            Number(_) | Ident(_) => false,
+            Op(l_expr, opcode, r_expr, _) => {
+                let divisor_may_be_zero =
+                    opcode.is_division() && !matches!(**r_expr, Number(n) if n != 0);
+
+                divisor_may_be_zero || l_expr.may_fail() || r_expr.may_fail()
+            }
+            Neg(tagged_expr) => tagged_expr.may_fail(),
+            Call(_, _) => true,
+        }
+    }
+
+    fn is_leaf(&self) -> bool {
+        matches!(self, TaggedExpr::Number(_) | TaggedExpr::Ident(_))
+    }
+}
+
+enum TaggedStmt<'a> {
+    Store(&'a String, Box<TaggedExpr<'a>>),
+    Print(Box<TaggedExpr<'a>>),
+    If(TaggedCond<'a>, Vec<TaggedStmt<'a>>, Vec<TaggedStmt<'a>>),
+    While(TaggedCond<'a>, Vec<TaggedStmt<'a>>),
+    Return(Box<TaggedExpr<'a>>),
+}
+
+enum TaggedCond<'a> {
+    Cmp(Box<TaggedExpr<'a>>, Relation, Box<TaggedExpr<'a>>),
+    And(Box<TaggedCond<'a>>, Box<TaggedCond<'a>>),
+    Or(Box<TaggedCond<'a>>, Box<TaggedCond<'a>>),
+    Not(Box<TaggedCond<'a>>),
+}
+
+/// Function declarations are left out, they're compiled on their own
+fn tag_stmts(stmts: &[Box<Stmt>]) -> Vec<TaggedStmt<'_>> {
+    stmts
+        .iter()
+        .filter(|stmt| !matches!(stmt.as_ref(), Stmt::SFn(_)))
+        .map(|stmt| match stmt.as_ref() {
+            Stmt::SAss(ident, expr) => TaggedStmt::Store(ident, Box::new(tag_expr(expr))),
+            Stmt::SExpr(expr) => TaggedStmt::Print(Box::new(tag_expr(expr))),
+            Stmt::SIf(cond, then, otherwise) => {
+                TaggedStmt::If(tag_cond(cond), tag_stmts(then), tag_stmts(otherwise))
+            }
+            Stmt::SWhile(cond, body) => TaggedStmt::While(tag_cond(cond), tag_stmts(body)),
+            Stmt::SReturn(expr, _) => TaggedStmt::Return(Box::new(tag_expr(expr))),
+            Stmt::SFn(_) => unreachable!("Function declarations are filtered out"),
+        })
+        .collect()
+}
+
+fn tag_cond(cond: &Cond) -> TaggedCond<'_> {
+    match cond {
+        Cond::Cmp(l_expr, relation, r_expr) => {
+            TaggedCond::Cmp(Box::new(tag_expr(l_expr)), *relation, Box::new(tag_expr(r_expr)))
+        }
+        Cond::And(l_cond, r_cond) => {
+            TaggedCond::And(Box::new(tag_cond(l_cond)), Box::new(tag_cond(r_cond)))
+        }
+        Cond::Or(l_cond, r_cond) => {
+            TaggedCond::Or(Box::new(tag_cond(l_cond)), Box::new(tag_cond(r_cond)))
+        }
+        Cond::Not(cond) => TaggedCond::Not(Box::new(tag_cond(cond))),
+    }
+}
+
+fn tag_expr(expr: &Expr) -> TaggedExpr<'_> {
+    match expr {
+        Expr::Number(n) => TaggedExpr::Number(*n),
+        Expr::Ident(id, _) => TaggedExpr::Ident(id),
+        Expr::Op(l_expr, opcode, r_expr) => {
+            let tagged_l_expr = tag_expr(l_expr);
+            let tagged_r_expr = tag_expr(r_expr);
+
+            let (l_need, r_need) = (
+                tagged_l_expr.get_register_need(),
+                tagged_r_expr.get_register_need(),
+            );
+
+            // Leaf on the right (or on the left of commutative operation) is used directly
+            // as instruction operand and needs no register of its own.
+            let need = if tagged_r_expr.is_leaf() {
+                l_need
+            } else if tagged_l_expr.is_leaf() && opcode.is_commutative() {
+                r_need
+            } else if l_need == r_need {
+                l_need + 1
+            } else {
+                cmp::max(l_need, r_need)
+            };
+
+            TaggedExpr::Op(
+                Box::new(tagged_l_expr),
+                opcode,
+                Box::new(tagged_r_expr),
+                need,
+            )
+        }
+        Expr::Neg(expr) => TaggedExpr::Neg(Box::new(tag_expr(expr))),
+        Expr::Call(name, args, _) => {
+            TaggedExpr::Call(name, args.iter().map(|arg| tag_expr(arg)).collect())
+        }
+    }
+}
+
+fn compile_tagged_stmts(stmts: &[TaggedStmt], state: &mut X86State) {
+    for stmt in stmts.iter() {
+        compile_tagged_stmt(stmt, state);
+    }
+}
+
+fn compile_tagged_stmt(stmt: &TaggedStmt, state: &mut X86State) {
+    use TaggedStmt::*;
+
+    match stmt {
+        Store(ident, expr) => {
+            compile_tagged_expr(expr, &REGISTERS, state);
+            state.store(REGISTERS[0], ident);
+        }
+        Print(expr) => {
+            compile_tagged_expr(expr, &REGISTERS, state);
+            state.print(REGISTERS[0]);
+        }
+        If(cond, then, otherwise) => {
+            let otherwise_label = state.new_label();
+            compile_cond_jump(cond, false, otherwise_label, state);
+
+            compile_tagged_stmts(then, state);
+
+            if otherwise.is_empty() {
+                state.label(otherwise_label);
+            } else {
+                let end_label = state.new_label();
+                state.jump(end_label);
+                state.label(otherwise_label);
+                compile_tagged_stmts(otherwise, state);
+                state.label(end_label);
+            }
+        }
+        // Condition is placed after the body, so each iteration ends with a single conditional jump
+        While(cond, body) => {
+            let body_label = state.new_label();
+            let cond_label = state.new_label();
+            state.jump(cond_label);
+
+            state.label(body_label);
+            compile_tagged_stmts(body, state);
+
+            state.label(cond_label);
+            compile_cond_jump(cond, true, body_label, state);
+        }
+        Return(expr) => {
+            compile_tagged_expr(expr, &REGISTERS, state);
+            state.ret(REGISTERS[0]);
+        }
+    }
+}
+
+/// Jumps to `label` when condition evaluates to `jump_if` and falls through otherwise, so `&&` and `||`
+/// only evaluate their right operand when it decides the result.
+fn compile_cond_jump(
+    cond: &TaggedCond,
+    jump_if: bool,
+    label: usize,
+    state: &mut X86State,
+) {
+    use TaggedCond::*;
+
+    match cond {
+        Cmp(l_expr, relation, r_expr) => {
+            let relation = if jump_if { *relation } else { relation.negate() };
+            let (source, destination) = compile_operands(l_expr, r_expr, false, &REGISTERS, state);
+
+            state.compare_and_jump(relation, &source, destination, label);
+        }
+        Not(cond) => compile_cond_jump(cond, !jump_if, label, state),
+        // Both operands decide only together, the first one deciding alone skips the second one
+        And(l_cond, r_cond) | Or(l_cond, r_cond) => {
+            let decides_alone = matches!(cond, Or(_, _));
+
+            if jump_if == decides_alone {
+                compile_cond_jump(l_cond, jump_if, label, state);
+                compile_cond_jump(r_cond, jump_if, label, state);
+            } else {
+                let skip_label = state.new_label();
+                compile_cond_jump(l_cond, !jump_if, skip_label, state);
+                compile_cond_jump(r_cond, jump_if, label, state);
+                state.label(skip_label);
+            }
+        }
+    }
+}
+
+fn leaf_operand(expr: &TaggedExpr, state: &X86State) -> Operand {
+    match expr {
+        TaggedExpr::Number(n) => Operand::Immediate(*n),
+        TaggedExpr::Ident(ident) => state.get_var_operand(ident),
+        TaggedExpr::Op(_, _, _, _) | TaggedExpr::Neg(_) | TaggedExpr::Call(_, _) => {
+            unreachable!("Operation is not a leaf")
+        }
+    }
+}
+
+/// Computes expression into registers[0], using the rest of registers as temporaries.
+fn compile_tagged_expr(
+    expr: &TaggedExpr,
+    registers: &[Register],
+    state: &mut X86State,
+) {
+    use TaggedExpr::*;
+
+    let target = registers[0];
+
+    match expr {
+        Number(_) | Ident(_) => {
+            let operand = leaf_operand(expr, state);
+            state.mov(&operand, target);
+        }
+        Neg(tagged_expr) => {
+            compile_tagged_expr(tagged_expr, registers, state);
+            state.neg(target);
+        }
+        Op(l_expr, opcode, r_expr, _) => {
+            let (source, destination) =
+                compile_operands(l_expr, r_expr, opcode.is_commutative(), registers, state);
+
+            state.arithmetic(opcode, &source, destination);
+
+            if destination != target {
+                state.mov(&Operand::Register(destination), target);
+            }
+        }
+        Call(name, args) => compile_call(name, args, registers, state),
+    }
+}
+
+/// Registers before `registers` hold values of enclosing expressions and are pushed first, then
+/// padding keeping %rsp 16-byte aligned at `call`, then arguments
+fn compile_call(
+    name: &str,
+    args: &[TaggedExpr],
+    registers: &[Register],
+    state: &mut X86State,
+) {
+    let label = state.function_labels[name];
+
+    let saved = &REGISTERS[..REGISTERS.len() - registers.len()];
+    for register in saved.iter() {
+        state.push(*register);
+    }
+
+    let padding = (state.stack_depth + 8 * args.len()) % 16;
+    state.reserve(padding as isize);
+
+    for arg in args.iter() {
+        compile_tagged_expr(arg, registers, state);
+        state.push(registers[0]);
+    }
+
+    state.instructions.push(format!("call .Lfn{}", label));
+    state.reserve(-((8 * args.len() + padding) as isize));
+
+    for register in saved.iter().rev() {
+        state.pop(*register);
+    }
+    state.mov(&Operand::Register(Register::Eax), registers[0]);
+}
+
+/// Evaluates both operands of binary operation (or comparison), returning `(source, destination)`
+/// such that `destination` holds the left operand and `source` the right one, unless `commutative`
+/// allows them the other way round. Destination is registers[0] or registers[1].
+/// Calls may print, so when one operand makes a call and the other one could fail or print too they
+/// are evaluated left to right; otherwise a call can still go after the other operand, as it can't
+/// change caller's variables.
+fn compile_operands(
+    l_expr: &TaggedExpr,
+    r_expr: &TaggedExpr,
+    commutative: bool,
+    registers: &[Register],
+    state: &mut X86State,
+) -> (Operand, Register) {
+    let target = registers[0];
+    let in_order = (l_expr.contains_call() && r_expr.may_fail())
+        || (r_expr.contains_call() && l_expr.may_fail());
+
+    if r_expr.is_leaf() {
+        compile_tagged_expr(l_expr, registers, state);
+        (leaf_operand(r_expr, state), target)
+    } else if l_expr.is_leaf() && commutative {
+        compile_tagged_expr(r_expr, registers, state);
+        (leaf_operand(l_expr, state), target)
+    } else if registers.len() == 1 && in_order {
+        compile_tagged_expr(l_expr, registers, state);
+        state.push(target);
+        compile_tagged_expr(r_expr, registers, state);
+        state.mov(&Operand::Register(target), Register::R11);
+        state.pop(target);
+        (Operand::Register(Register::R11), target)
+    } else if registers.len() == 1 {
+        // Out of registers: keep right result on machine stack while computing left one
+        compile_tagged_expr(r_expr, registers, state);
+        state.push(target);
+        compile_tagged_expr(l_expr, registers, state);
+        state.pop(Register::R11);
+        (Operand::Register(Register::R11), target)
+    } else if in_order || l_expr.get_register_need() >= r_expr.get_register_need() {
+        compile_tagged_expr(l_expr, registers, state);
+        compile_tagged_expr(r_expr, &registers[1..], state);
+        (Operand::Register(registers[1]), target)
+    } else {
+        // Right subtree is evaluated first so it can use all the registers
+        compile_tagged_expr(r_expr, registers, state);
+        compile_tagged_expr(l_expr, &registers[1..], state);
+
+        if commutative {
+            (Operand::Register(registers[1]), target)
+        } else {
+            (Operand::Register(target), registers[1])
+        }
+    }
+}
@@ -0,0 +1,125 @@
+//! Helpers shared by integration tests: running generated code with external toolchains.
+
+#![allow(dead_code)]
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use instant::ast::Stmt;
+use instant::{jvm, wasm, x86};
+
+/// Fresh scratch directory for files produced by external toolchains.
+pub fn scratch_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("instant-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).expect("Unable to create scratch directory");
+    dir
+}
+
+pub fn tool_available(tool: &str, version_arg: &str) -> bool {
+    let available = Command::new(tool).arg(version_arg).output().is_ok();
+    if !available {
+        eprintln!("{} not found, skipping", tool);
+    }
+    available
+}
+
+pub fn run(command: &mut Command) -> String {
+    let output = command.output().expect("Unable to run command");
+    assert!(
+        output.status.success(),
+        "{:?} failed: {}",
+        command,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout).unwrap()
+}
+
+/// Runs command which is expected to fail, returning what it printed before
+pub fn run_failing(command: &mut Command) -> String {
+    let output = command.output().expect("Unable to run command");
+    assert!(!output.status.success(), "{:?} did not fail", command);
+    String::from_utf8(output.stdout).unwrap()
+}
+
+pub fn format_output(values: &[i32]) -> String {
+    values.iter().map(|value| format!("{}\n", value)).collect()
+}
+
+pub fn llvm_available() -> bool {
+    tool_available("lli", "--version") && tool_available("llvm-link", "--version")
+}
+
+/// Links generated LLVM IR with runtime and runs it with `lli`
+pub fn run_llvm(dir: &Path, name: &str, code: &str) -> String {
+    let runtime = Path::new(env!("CARGO_MANIFEST_DIR")).join("lib/runtime.bc");
+    let ll_path = dir.join(format!("{}.ll", name));
+    let bc_path = dir.join(format!("{}.bc", name));
+    fs::write(&ll_path, code).unwrap();
+
+    run(Command::new("llvm-link")
+        .arg("-o")
+        .arg(&bc_path)
+        .arg(&ll_path)
+        .arg(&runtime));
+    run(Command::new("lli").arg(&bc_path))
+}
+
+pub fn jvm_available() -> bool {
+    tool_available("java", "-version")
+}
+
+pub fn run_jvm(dir: &Path, name: &str, stmts: &[Box<Stmt>]) -> String {
+    run(&mut jvm_command(dir, name, stmts))
+}
+
+/// Writes class file and returns command running it
+pub fn jvm_command(dir: &Path, name: &str, stmts: &[Box<Stmt>]) -> Command {
+    let class = jvm::compile_class(stmts, name);
+    fs::write(dir.join(format!("{}.class", name)), class).unwrap();
+
+    let mut command = Command::new("java");
+    command.arg("-Xverify:all").arg("-cp").arg(dir).arg(name);
+    command
+}
+
+pub fn x86_available() -> bool {
+    tool_available("gcc", "--version")
+}
+
+pub fn run_x86(dir: &Path, name: &str, stmts: &[Box<Stmt>]) -> String {
+    run(&mut x86_command(dir, name, stmts))
+}
+
+/// Assembles and links program, returning command running the executable
+pub fn x86_command(dir: &Path, name: &str, stmts: &[Box<Stmt>]) -> Command {
+    let s_path = dir.join(format!("{}.s", name));
+    let executable_path = dir.join(name);
+    fs::write(&s_path, x86::compile(stmts)).unwrap();
+
+    run(Command::new("gcc").arg("-o").arg(&executable_path).arg(&s_path));
+    Command::new(&executable_path)
+}
+
+pub fn wasm_available() -> bool {
+    tool_available("node", "--version")
+}
+
+pub fn run_wasm(dir: &Path, name: &str, stmts: &[Box<Stmt>]) -> String {
+    run(&mut wasm_command(dir, name, stmts))
+}
+
+/// Writes module and returns command instantiating it and running its `main`
+pub fn wasm_command(dir: &Path, name: &str, stmts: &[Box<Stmt>]) -> Command {
+    let runner = "const bytes = require('fs').readFileSync(process.argv[1]);\
+        WebAssembly.instantiate(bytes, { env: { printInt: (n) => console.log(n) } })\
+        .then(({ instance }) => instance.exports.main());";
+    let wasm_path = dir.join(format!("{}.wasm", name));
+    fs::write(&wasm_path, wasm::compile_wasm(stmts)).unwrap();
+
+    let mut command = Command::new("node");
+    command.arg("-e").arg(runner).arg(&wasm_path);
+    command
+}
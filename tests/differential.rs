@@ -0,0 +1,458 @@
+//! Differential testing of the backends on randomly generated programs.
+//!
+//! Programs are built from `ast::Stmt`/`ast::Expr` shapes, pretty-printed, parsed back and then run by
+//! the interpreter, through LLVM (`lli`, both memory and SSA form) and on the JVM; all must print
+//! the same values.
+//! Optimised programs must behave exactly as the original ones in the interpreter.
+//! `INSTANT_FUZZ_SEED` and `INSTANT_FUZZ_ITERATIONS` environment variables control the run.
+
+use std::collections::HashMap;
+use std::env;
+
+use instant::ast::{self, Cond, Expr, Function, Opcode, Relation, Span, Stmt};
+use instant::interp::{self, Interpreter, RuntimeError};
+use instant::{jvm, llvm, opt};
+
+mod common;
+
+// Includes names close to those LLVM backend generates for registers and allocas
+const VARIABLES: [&str; 8] = ["a", "b", "c", "x1", "r", "r1", "loc_r", "var_2"];
+// Loop counters, one per nesting level; never assigned by generated statements, so loops terminate
+const COUNTERS: [&str; 2] = ["i", "L1"];
+// Includes names backends use themselves; each function may call only the ones before it, so calls end
+const FUNCTIONS: [&str; 3] = ["f", "main", "printInt"];
+const PARAMS: [&str; 3] = ["p", "a", "q"];
+
+/// Functions generated code may call, with their arity, and whether it is inside one of them
+#[derive(Clone, Copy)]
+struct Scope<'a> {
+    functions: &'a [(&'static str, usize)],
+    in_function: bool,
+}
+
+/// xorshift64*, good enough for generating test programs and reproducible from a seed
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+}
+
+fn gen_number(rng: &mut Rng) -> i32 {
+    // Cover iconst, bipush, sipush and ldc ranges
+    let bound = match rng.below(4) {
+        0 => 6,
+        1 => 128,
+        2 => 40_000,
+        _ => i32::MAX as u64 + 1,
+    };
+    let n = rng.below(bound) as i32;
+
+    // Negative literals, down to i32::MIN
+    if rng.below(4) == 0 {
+        -n - 1
+    } else {
+        n
+    }
+}
+
+fn gen_expr(rng: &mut Rng, declared: &[&str], depth: usize, scope: Scope) -> Box<Expr> {
+    if depth == 0 || rng.below(5) == 0 {
+        return if !declared.is_empty() && rng.below(2) == 0 {
+            let ident = declared[rng.below(declared.len() as u64) as usize];
+            Box::new(Expr::Ident(ident.to_string(), Span::new(0, 0)))
+        } else {
+            Box::new(Expr::Number(gen_number(rng)))
+        };
+    }
+
+    if rng.below(8) == 0 {
+        return Box::new(Expr::Neg(gen_expr(rng, declared, depth - 1, scope)));
+    }
+
+    if !scope.functions.is_empty() && rng.below(6) == 0 {
+        let (name, arity) = scope.functions[rng.below(scope.functions.len() as u64) as usize];
+        let args = (0..arity)
+            .map(|_| gen_expr(rng, declared, depth - 1, scope))
+            .collect();
+        return Box::new(Expr::Call(name.to_string(), args, Span::new(0, 0)));
+    }
+
+    let opcodes = [
+        Opcode::Add,
+        Opcode::Sub,
+        Opcode::Mul,
+        Opcode::Div,
+        Opcode::Rem,
+        Opcode::And,
+        Opcode::Or,
+        Opcode::Xor,
+        Opcode::Shl,
+        Opcode::Shr,
+    ];
+    let opcode = opcodes[rng.below(opcodes.len() as u64) as usize];
+
+    Box::new(Expr::Op(
+        gen_expr(rng, declared, depth - 1, scope),
+        opcode,
+        gen_expr(rng, declared, depth - 1, scope),
+    ))
+}
+
+fn gen_cond(rng: &mut Rng, declared: &[&str], depth: usize, scope: Scope) -> Box<Cond> {
+    let relations = [
+        Relation::Lt,
+        Relation::Le,
+        Relation::Gt,
+        Relation::Ge,
+        Relation::Eq,
+        Relation::Ne,
+    ];
+
+    match rng.below(if depth == 0 { 1 } else { 6 }) {
+        0..=2 => {
+            let relation = relations[rng.below(relations.len() as u64) as usize];
+            // Shallow operands make equal values (and so both branches) likely enough
+            let depth = rng.below(3) as usize;
+            Box::new(Cond::Cmp(
+                gen_expr(rng, declared, depth, scope),
+                relation,
+                gen_expr(rng, declared, depth, scope),
+            ))
+        }
+        3 => Box::new(Cond::And(
+            gen_cond(rng, declared, depth - 1, scope),
+            gen_cond(rng, declared, depth - 1, scope),
+        )),
+        4 => Box::new(Cond::Or(
+            gen_cond(rng, declared, depth - 1, scope),
+            gen_cond(rng, declared, depth - 1, scope),
+        )),
+        _ => Box::new(Cond::Not(gen_cond(rng, declared, depth - 1, scope))),
+    }
+}
+
+#[allow(clippy::vec_box)]
+fn gen_program(rng: &mut Rng) -> Vec<Box<Stmt>> {
+    let mut stmts = vec![];
+    let mut functions = vec![];
+
+    for name in FUNCTIONS.iter().take(rng.below(FUNCTIONS.len() as u64 + 1) as usize) {
+        let params = &PARAMS[..rng.below(PARAMS.len() as u64 + 1) as usize];
+        let scope = Scope {
+            functions: &functions,
+            in_function: true,
+        };
+
+        let mut declared = params.to_vec();
+        let mut body = gen_stmts(rng, &mut declared, 1, scope);
+        let expr = gen_expr(rng, &declared, 3, scope);
+        body.push(Box::new(Stmt::SReturn(expr, Span::new(0, 0))));
+
+        stmts.push(Box::new(Stmt::SFn(Box::new(Function {
+            name: name.to_string(),
+            params: params.iter().map(|param| param.to_string()).collect(),
+            param_spans: vec![Span::new(0, 0); params.len()],
+            body,
+            span: Span::new(0, 0),
+        }))));
+        functions.push((*name, params.len()));
+    }
+
+    let scope = Scope {
+        functions: &functions,
+        in_function: false,
+    };
+    stmts.append(&mut gen_stmts(rng, &mut vec![], 2, scope));
+
+    stmts
+}
+
+/// `declared` ends up holding variables assigned on every path through generated statements
+#[allow(clippy::vec_box)]
+fn gen_stmts(
+    rng: &mut Rng,
+    declared: &mut Vec<&'static str>,
+    nesting: usize,
+    scope: Scope,
+) -> Vec<Box<Stmt>> {
+    let mut stmts = vec![];
+
+    for _ in 0..1 + rng.below(12) {
+        if nesting > 0 && rng.below(8) == 0 {
+            stmts.append(&mut gen_loop(rng, declared, nesting, scope));
+            continue;
+        }
+
+        if nesting > 0 && rng.below(6) == 0 {
+            let cond = gen_cond(rng, declared, 2, scope);
+
+            let mut declared_then = declared.clone();
+            let mut then = gen_stmts(rng, &mut declared_then, nesting - 1, scope);
+            let otherwise = match rng.below(2) {
+                0 => vec![],
+                _ => gen_stmts(rng, declared, nesting - 1, scope),
+            };
+
+            // Branch which returns doesn't matter for variables assigned afterwards
+            if scope.in_function && rng.below(3) == 0 {
+                let expr = gen_expr(rng, &declared_then, 3, scope);
+                then.push(Box::new(Stmt::SReturn(expr, Span::new(0, 0))));
+            } else {
+                declared.retain(|ident| declared_then.contains(ident));
+            }
+
+            stmts.push(Box::new(Stmt::SIf(cond, then, otherwise)));
+            continue;
+        }
+
+        let depth = rng.below(7) as usize;
+        let expr = gen_expr(rng, declared, depth, scope);
+
+        if rng.below(5) < 3 {
+            let ident = VARIABLES[rng.below(VARIABLES.len() as u64) as usize];
+            stmts.push(Box::new(Stmt::SAss(ident.to_string(), expr)));
+            if !declared.contains(&ident) {
+                declared.push(ident);
+            }
+        } else {
+            stmts.push(Box::new(Stmt::SExpr(expr)));
+        }
+    }
+
+    stmts
+}
+
+/// `counter = 0; while (counter < bound [&& cond]) { ...; counter = counter + 1 }`
+#[allow(clippy::vec_box)]
+fn gen_loop(
+    rng: &mut Rng,
+    declared: &mut Vec<&'static str>,
+    nesting: usize,
+    scope: Scope,
+) -> Vec<Box<Stmt>> {
+    let counter = COUNTERS[nesting - 1];
+    let ident = || Box::new(Expr::Ident(counter.to_string(), Span::new(0, 0)));
+
+    if !declared.contains(&counter) {
+        declared.push(counter);
+    }
+
+    let bound = Box::new(Expr::Number(rng.below(5) as i32));
+    let mut cond = Box::new(Cond::Cmp(ident(), Relation::Lt, bound));
+    if rng.below(2) == 0 {
+        cond = Box::new(Cond::And(cond, gen_cond(rng, declared, 1, scope)));
+    }
+
+    let mut body = gen_stmts(rng, &mut declared.clone(), nesting - 1, scope);
+    let increment = Box::new(Expr::Op(ident(), Opcode::Add, Box::new(Expr::Number(1))));
+    body.push(Box::new(Stmt::SAss(counter.to_string(), increment)));
+
+    vec![
+        Box::new(Stmt::SAss(counter.to_string(), Box::new(Expr::Number(0)))),
+        Box::new(Stmt::SWhile(cond, body)),
+    ]
+}
+
+fn print_program(stmts: &[Box<Stmt>]) -> String {
+    stmts
+        .iter()
+        .map(|stmt| stmt.to_string())
+        .collect::<Vec<String>>()
+        .join(";\n")
+}
+
+/// Programs dividing (or taking remainder) by zero are undefined in LLVM, so they are not compared. Only divisions actually executed count.
+fn has_undefined_division(stmts: &[Box<Stmt>]) -> bool {
+    type Functions<'a> = HashMap<&'a str, &'a Function>;
+
+    fn eval(expr: &Expr, vars: &HashMap<&str, i32>, functions: &Functions) -> Option<i32> {
+        match expr {
+            Expr::Number(n) => Some(*n),
+            Expr::Ident(ident, _) => vars.get(ident.as_str()).copied(),
+            Expr::Op(l_expr, opcode, r_expr) => {
+                let (l, r) = (eval(l_expr, vars, functions)?, eval(r_expr, vars, functions)?);
+                if opcode.is_division() && r == 0 {
+                    return None;
+                }
+                interp::eval_op(*opcode, l, r).ok()
+            }
+            Expr::Neg(expr) => Some(eval(expr, vars, functions)?.wrapping_neg()),
+            Expr::Call(name, args, _) => {
+                let function = functions[name.as_str()];
+                let mut callee_vars = HashMap::new();
+                for (param, arg) in function.params.iter().zip(args.iter()) {
+                    callee_vars.insert(param.as_str(), eval(arg, vars, functions)?);
+                }
+                run(&function.body, &mut callee_vars, functions)?
+            }
+        }
+    }
+
+    fn eval_cond(cond: &Cond, vars: &HashMap<&str, i32>, functions: &Functions) -> Option<bool> {
+        match cond {
+            Cond::Cmp(l_expr, relation, r_expr) => Some(relation.holds(
+                eval(l_expr, vars, functions)?,
+                eval(r_expr, vars, functions)?,
+            )),
+            Cond::And(l_cond, r_cond) => {
+                Some(eval_cond(l_cond, vars, functions)? && eval_cond(r_cond, vars, functions)?)
+            }
+            Cond::Or(l_cond, r_cond) => {
+                Some(eval_cond(l_cond, vars, functions)? || eval_cond(r_cond, vars, functions)?)
+            }
+            Cond::Not(cond) => Some(!eval_cond(cond, vars, functions)?),
+        }
+    }
+
+    /// Value returned, if any, or `None` on undefined division
+    fn run<'a>(
+        stmts: &'a [Box<Stmt>],
+        vars: &mut HashMap<&'a str, i32>,
+        functions: &Functions,
+    ) -> Option<Option<i32>> {
+        for stmt in stmts.iter() {
+            let returned = match stmt.as_ref() {
+                Stmt::SAss(ident, expr) => {
+                    vars.insert(ident, eval(expr, vars, functions)?);
+                    None
+                }
+                Stmt::SExpr(expr) => {
+                    eval(expr, vars, functions)?;
+                    None
+                }
+                Stmt::SIf(cond, then, otherwise) => match eval_cond(cond, vars, functions)? {
+                    true => run(then, vars, functions)?,
+                    false => run(otherwise, vars, functions)?,
+                },
+                Stmt::SWhile(cond, body) => loop {
+                    if !eval_cond(cond, vars, functions)? {
+                        break None;
+                    }
+                    if let Some(value) = run(body, vars, functions)? {
+                        break Some(value);
+                    }
+                },
+                Stmt::SFn(_) => None,
+                Stmt::SReturn(expr, _) => Some(eval(expr, vars, functions)?),
+            };
+
+            if returned.is_some() {
+                return Some(returned);
+            }
+        }
+
+        Some(None)
+    }
+
+    let functions = ast::functions(stmts)
+        .map(|function| (function.name.as_str(), function))
+        .collect();
+
+    run(stmts, &mut HashMap::new(), &functions).is_none()
+}
+
+fn env_or(name: &str, default: u64) -> u64 {
+    env::var(name)
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(default)
+}
+
+#[test]
+fn backends_agree_on_random_programs() {
+    let seed = env_or("INSTANT_FUZZ_SEED", 0x1257_f00d);
+    let iterations = env_or("INSTANT_FUZZ_ITERATIONS", 25);
+
+    let llvm = common::llvm_available();
+    let jvm = common::jvm_available();
+    let dir = common::scratch_dir("differential");
+
+    let mut rng = Rng(seed);
+    let mut tested = 0;
+
+    while tested < iterations {
+        let generated = gen_program(&mut rng);
+        if has_undefined_division(&generated) {
+            continue;
+        }
+
+        let source = print_program(&generated);
+        let stmts = instant::parse_and_check(&source)
+            .unwrap_or_else(|_| panic!("Generated program does not parse:\n{}", source));
+        assert_eq!(print_program(&stmts), source, "Pretty-printing is not stable");
+
+        let name = format!("Fuzz{}", tested);
+        let expected = common::format_output(&interp::run(&stmts).unwrap());
+        let report = |backend: &str, actual: &str| {
+            format!(
+                "{} differs from interpreter (seed {}, program {}):\n{}\n--- expected\n{}--- actual\n{}",
+                backend, seed, tested, source, expected, actual
+            )
+        };
+
+        if llvm {
+            let actual = common::run_llvm(&dir, &name, &llvm::compile(&stmts));
+            assert!(actual == expected, "{}", report("llvm", &actual));
+
+            let actual = common::run_llvm(&dir, &name, &llvm::compile_ssa(&stmts));
+            assert!(actual == expected, "{}", report("llvm-ssa", &actual));
+        }
+
+        // Compiling checks stack limits against simulated instructions, even without Java installed
+        let stats = jvm::peephole_stats(&stmts);
+        assert!(stats.after <= stats.before, "{}", report("jvm peephole", "longer code\n"));
+
+        if jvm {
+            let actual = common::run_jvm(&dir, &name, &stmts);
+            assert!(actual == expected, "{}", report("jvm", &actual));
+        }
+
+        tested += 1;
+    }
+}
+
+/// Printed values and runtime error, if any
+fn interpret(stmts: &[Box<Stmt>]) -> (Vec<i32>, Option<RuntimeError>) {
+    let mut interpreter = Interpreter::new();
+    let result = interpreter.run(stmts);
+
+    (interpreter.output().to_vec(), result.err())
+}
+
+#[test]
+fn optimizations_preserve_behaviour() {
+    let seed = env_or("INSTANT_FUZZ_SEED", 0x1257_f00d);
+    let iterations = env_or("INSTANT_FUZZ_ITERATIONS", 25) * 40;
+
+    let mut rng = Rng(seed);
+
+    for i in 0..iterations {
+        // Division by zero is kept here, optimisations must not remove or introduce it
+        let stmts = gen_program(&mut rng);
+        let source = print_program(&stmts);
+
+        let expected = interpret(&stmts);
+        let (optimized, _) = opt::optimize(stmts);
+        let actual = interpret(&optimized);
+
+        assert!(
+            actual == expected,
+            "Optimised program behaves differently (seed {}, program {}):\n{}\n--- optimised\n{}\n--- expected\n{:?}\n--- actual\n{:?}",
+            seed,
+            i,
+            source,
+            print_program(&optimized),
+            expected,
+            actual
+        );
+    }
+}
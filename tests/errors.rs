@@ -0,0 +1,287 @@
+//! Programs rejected by the parser or semantic analysis.
+//!
+//! Each test checks which errors are reported, where, and how `CompileError::render` shows them.
+
+use instant::ast::Span;
+use instant::interp::{Interpreter, RuntimeError};
+use instant::{CompileError, ErrorKind};
+
+fn errors(source: &str) -> Vec<CompileError> {
+    match instant::parse_and_check(source) {
+        Ok(_) => panic!("Program compiles:\n{}", source),
+        Err(errors) => errors,
+    }
+}
+
+fn rendered(source: &str) -> String {
+    errors(source)
+        .iter()
+        .map(|error| error.render("test.ins", source))
+        .collect()
+}
+
+#[test]
+fn syntax_error() {
+    let source = "a = 1;\nb = a + ;\n";
+    let errors = errors(source);
+
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(errors[0].kind, ErrorKind::Syntax(_)));
+    let message = errors[0].kind.to_string();
+    assert!(message.starts_with("unexpected token `;`"), "{}", message);
+    assert_eq!(errors[0].span, Span::new(15, 16));
+    assert_eq!(errors[0].line_col(source), (2, 9));
+}
+
+#[test]
+fn unexpected_end_of_file() {
+    let errors = errors("a = (1 + 2");
+
+    assert!(matches!(errors[0].kind, ErrorKind::Syntax(_)));
+    let message = errors[0].kind.to_string();
+    assert!(message.starts_with("unexpected end of file"), "{}", message);
+    assert_eq!(errors[0].span, Span::new(10, 10));
+}
+
+#[test]
+fn integer_overflow() {
+    let cases = [
+        ("2147483648", Span::new(4, 14)),
+        ("-2147483649", Span::new(4, 15)),
+        ("99999999999999999999", Span::new(4, 24)),
+    ];
+
+    for (literal, span) in cases.iter() {
+        let errors = errors(&format!("x = {}", literal));
+        let expected = CompileError::new(ErrorKind::IntegerOverflow(literal.to_string()), *span);
+        assert_eq!(errors, vec![expected]);
+    }
+
+    assert!(instant::parse_and_check("x = -2147483648; x = 2147483647").is_ok());
+}
+
+#[test]
+fn undeclared_variable() {
+    let errors = errors("a = 1;\nb = a + c;\nc = 2;\nc + d");
+
+    assert_eq!(
+        errors,
+        vec![
+            CompileError::new(ErrorKind::UndeclaredVariable(String::from("c")), Span::new(15, 16)),
+            CompileError::new(ErrorKind::UndeclaredVariable(String::from("d")), Span::new(29, 30)),
+        ]
+    );
+}
+
+#[test]
+fn render_points_at_span() {
+    assert_eq!(
+        rendered("a = 1;\nb = a + xyz;\n"),
+        "error: use of undeclared variable `xyz`\n \
+         --> test.ins:2:9\n  \
+         |\n\
+         2 | b = a + xyz;\n  \
+         |         ^^^\n"
+    );
+}
+
+#[test]
+fn render_names_expected_tokens() {
+    assert_eq!(
+        rendered("a = 1;\nb = a + ;\n"),
+        "error: unexpected token `;`, expected one of `(`, `-`, number, identifier\n \
+         --> test.ins:2:9\n  \
+         |\n\
+         2 | b = a + ;\n  \
+         |         ^\n"
+    );
+}
+
+#[test]
+fn render_keeps_tabs_before_caret() {
+    assert_eq!(
+        rendered("a = 1;\nif (a < 2) {\n\t\ta + b\n}\n"),
+        "error: use of undeclared variable `b`\n \
+         --> test.ins:3:7\n  \
+         |\n\
+         3 | \t\ta + b\n  \
+         | \t\t    ^\n"
+    );
+}
+
+#[test]
+fn render_widens_gutter_for_long_line_numbers() {
+    let source = format!("{}x", "1;\n".repeat(11));
+
+    assert_eq!(
+        rendered(&source),
+        "error: use of undeclared variable `x`\n  \
+         --> test.ins:12:1\n   \
+         |\n\
+         12 | x\n   \
+         | ^\n"
+    );
+}
+
+/// Messages of reported errors with their line and column
+fn located(source: &str) -> Vec<(String, (usize, usize))> {
+    errors(source)
+        .iter()
+        .map(|error| (error.kind.to_string(), error.line_col(source)))
+        .collect()
+}
+
+fn undeclared(ident: &str, line: usize, col: usize) -> (String, (usize, usize)) {
+    (format!("use of undeclared variable `{}`", ident), (line, col))
+}
+
+#[test]
+fn variable_used_in_its_own_assignment() {
+    assert_eq!(located("x = x + 1"), vec![undeclared("x", 1, 5)]);
+}
+
+#[test]
+fn errors_are_reported_in_source_order() {
+    let source = "a = b;\nc = a * d;\na + e";
+
+    assert_eq!(
+        located(source),
+        vec![undeclared("b", 1, 5), undeclared("d", 2, 9), undeclared("e", 3, 5)]
+    );
+}
+
+#[test]
+fn variable_assigned_in_one_branch() {
+    let source = "c = 1;\n\
+                  if (c < 2) { a = 1; b = 1 } else { b = 2 };\n\
+                  b;\n\
+                  a";
+
+    assert_eq!(located(source), vec![undeclared("a", 4, 1)]);
+}
+
+#[test]
+fn variable_assigned_in_branch_used_in_condition() {
+    let source = "c = 1;\n\
+                  if (c < 2 && d > 0) { d = 1 } else { e = d }";
+
+    assert_eq!(located(source), vec![undeclared("d", 2, 14), undeclared("d", 2, 42)]);
+}
+
+#[test]
+fn variable_assigned_in_loop_body() {
+    let source = "i = 0;\n\
+                  while (i < 3) { v; v = i; i = i + 1 }\n\
+                  v";
+
+    assert_eq!(located(source), vec![undeclared("v", 2, 17), undeclared("v", 3, 1)]);
+}
+
+#[test]
+fn function_body_sees_only_its_parameters() {
+    let source = "x = 1;\n\
+                  fn f(p) { y = p + x; return y }\n\
+                  f(x) + y";
+
+    assert_eq!(located(source), vec![undeclared("x", 2, 19), undeclared("y", 3, 8)]);
+}
+
+#[test]
+fn returning_branch_does_not_limit_assigned_variables() {
+    let valid = "fn f(p) { if (p < 0) { return 0 } else { q = p } return q }\n\
+                 fn g(p) { if (p < 0) { q = p } else { return 0 } return q }\n\
+                 fn h(p) { if (p < 0) { return 0 } else { return 1 } }\n\
+                 f(1) + g(2) + h(3)";
+    assert!(instant::parse_and_check(valid).is_ok());
+
+    let source = "fn f(p) {\n    if (p < 0) { q = p; return q }\n    return q\n}";
+    assert_eq!(located(source), vec![undeclared("q", 3, 12)]);
+}
+
+#[test]
+fn undefined_function() {
+    assert_eq!(
+        rendered("x = 1;\nh(x)"),
+        "error: call to undefined function `h`\n \
+         --> test.ins:2:1\n  \
+         |\n\
+         2 | h(x)\n  \
+         | ^\n"
+    );
+}
+
+#[test]
+fn argument_count() {
+    assert_eq!(
+        rendered("fn g(x) { return x }\ng(1, 2);\nfn two(a, b) { return a }\ntwo(3)"),
+        "error: function `g` takes 1 argument but 2 were supplied\n \
+         --> test.ins:2:1\n  \
+         |\n\
+         2 | g(1, 2);\n  \
+         | ^\n\
+         error: function `two` takes 2 arguments but 1 was supplied\n \
+         --> test.ins:4:1\n  \
+         |\n\
+         4 | two(3)\n  \
+         | ^^^\n"
+    );
+}
+
+#[test]
+fn duplicate_function() {
+    assert_eq!(
+        rendered("fn f() { return 1 }\nfn f() { return 2 }\nf()"),
+        "error: function `f` is defined more than once\n \
+         --> test.ins:2:4\n  \
+         |\n\
+         2 | fn f() { return 2 }\n  \
+         |    ^\n"
+    );
+}
+
+#[test]
+fn duplicate_parameter() {
+    assert_eq!(
+        rendered("fn add(a, b, a) { return a }\nadd(1, 2, 3)"),
+        "error: parameter `a` is declared more than once\n \
+         --> test.ins:1:14\n  \
+         |\n\
+         1 | fn add(a, b, a) { return a }\n  \
+         |              ^\n"
+    );
+}
+
+#[test]
+fn missing_return() {
+    assert_eq!(
+        rendered("fn g(x) {\n\tif (x < 1) { return 1 }\n\twhile (x > 0) { return 2 }\n}\ng(0)"),
+        "error: function `g` may end without returning a value\n \
+         --> test.ins:1:4\n  \
+         |\n\
+         1 | fn g(x) {\n  \
+         |    ^\n"
+    );
+}
+
+/// Interpreter runs programs which skipped `semantic::check`, failing only on paths actually taken
+#[test]
+fn missing_return_at_run_time() {
+    let stmts = instant::parse("fn g(x) {\n\tif (x < 1) { return 1 }\n}\ng(0);\ng(1)").unwrap();
+
+    let mut interpreter = Interpreter::new();
+
+    assert_eq!(interpreter.run(&stmts), Err(RuntimeError::MissingReturn(String::from("g"))));
+    assert_eq!(interpreter.output(), &[1]);
+}
+
+#[test]
+fn return_outside_function() {
+    assert_eq!(
+        rendered("a = 1;\nif (a < 2) { return a }"),
+        "error: `return` outside of function\n \
+         --> test.ins:2:14\n  \
+         |\n\
+         2 | if (a < 2) { return a }\n  \
+         |              ^^^^^^\n"
+    );
+}
@@ -0,0 +1,348 @@
+//! Golden-file tests over `foo/bar/testNN.ins` programs.
+//!
+//! Every program is compiled through each backend, run (in-process for the interpreter, with the
+//! external toolchain for the others when it is installed) and its stdout compared with `testNN.output`.
+//! Generated `.ir`, `.ll` and `.j` files are compared with snapshots next to the program;
+//! run with `UPDATE_SNAPSHOTS=1` to regenerate them.
+
+use std::env;
+use std::ffi::OsStr;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use instant::ast::Stmt;
+use instant::{interp, ir, jvm, llvm};
+
+mod common;
+
+struct TestCase {
+    name: String,
+    source: PathBuf,
+    expected_output: String,
+}
+
+impl TestCase {
+    #[allow(clippy::vec_box)]
+    fn stmts(&self) -> Vec<Box<Stmt>> {
+        let contents = fs::read_to_string(&self.source).expect("Error reading file");
+
+        instant::parse_and_check(&contents).unwrap_or_else(|errors| {
+            let rendered: String = errors
+                .iter()
+                .map(|error| error.render(&self.name, &contents))
+                .collect();
+            panic!("{} does not compile:\n{}", self.name, rendered)
+        })
+    }
+
+    fn snapshot_path(&self, extension: &str) -> PathBuf {
+        self.source.with_extension(extension)
+    }
+}
+
+fn test_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("foo/bar")
+}
+
+fn test_cases() -> Vec<TestCase> {
+    let mut cases: Vec<TestCase> = fs::read_dir(test_dir())
+        .expect("Unable to read test directory")
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension() == Some(OsStr::new("ins")))
+        .map(|source| {
+            let name = source.file_stem().unwrap().to_str().unwrap().to_string();
+            let expected_output = fs::read_to_string(source.with_extension("output"))
+                .unwrap_or_else(|_| panic!("Missing expected output for {}", name));
+
+            TestCase {
+                name,
+                source,
+                expected_output,
+            }
+        })
+        .collect();
+
+    cases.sort_by(|a, b| a.name.cmp(&b.name));
+    assert!(!cases.is_empty(), "No test programs found");
+
+    cases
+}
+
+/// Runs `check` for every test case and reports all mismatches at once.
+fn check_all<F: Fn(&TestCase) -> String>(backend: &str, check: F) {
+    let failures: Vec<String> = test_cases()
+        .iter()
+        .filter_map(|case| {
+            let actual = check(case);
+            if actual == case.expected_output {
+                None
+            } else {
+                Some(format!(
+                    "{} [{}]\n--- expected\n{}--- actual\n{}",
+                    case.name, backend, case.expected_output, actual
+                ))
+            }
+        })
+        .collect();
+
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+/// Compares generated code with snapshot, or rewrites snapshot when UPDATE_SNAPSHOTS is set.
+fn check_snapshot(case: &TestCase, extension: &str, code: &str) -> Option<String> {
+    let path = case.snapshot_path(extension);
+
+    if env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        fs::write(&path, code).expect("Unable to write snapshot");
+        return None;
+    }
+
+    match fs::read_to_string(&path) {
+        Ok(expected) if expected == code => None,
+        Ok(_) => Some(format!("{}.{} differs from generated code", case.name, extension)),
+        Err(_) => Some(format!("{}.{} snapshot missing", case.name, extension)),
+    }
+}
+
+#[test]
+fn interpreter() {
+    check_all("interp", |case| {
+        common::format_output(&interp::run(&case.stmts()).expect("Runtime error"))
+    });
+}
+
+#[test]
+fn snapshots() {
+    let failures: Vec<String> = test_cases()
+        .iter()
+        .flat_map(|case| {
+            let stmts = case.stmts();
+            let program = ir::eliminate_common_subexpressions(ir::lower(&stmts));
+            let ll = llvm::compile(&stmts);
+            let j = jvm::compile(&stmts, &case.name);
+
+            vec![
+                check_snapshot(case, "ir", &program.to_string()),
+                check_snapshot(case, "ll", &ll),
+                check_snapshot(case, "j", &j),
+            ]
+        })
+        .flatten()
+        .collect();
+
+    assert!(
+        failures.is_empty(),
+        "{}\nRun with UPDATE_SNAPSHOTS=1 to regenerate",
+        failures.join("\n")
+    );
+}
+
+#[test]
+fn llvm() {
+    if !common::llvm_available() {
+        return;
+    }
+
+    let dir = common::scratch_dir("golden-llvm");
+    check_all("llvm", |case| {
+        common::run_llvm(&dir, &case.name, &llvm::compile(&case.stmts()))
+    });
+}
+
+#[test]
+fn llvm_ssa() {
+    if !common::llvm_available() {
+        return;
+    }
+
+    let dir = common::scratch_dir("golden-llvm-ssa");
+    check_all("llvm-ssa", |case| {
+        let code = llvm::compile_ssa(&case.stmts());
+        assert!(!code.contains("alloca"), "SSA output uses memory");
+        common::run_llvm(&dir, &case.name, &code)
+    });
+}
+
+#[test]
+fn jvm() {
+    if !common::jvm_available() {
+        return;
+    }
+
+    let dir = common::scratch_dir("golden-jvm");
+    check_all("jvm", |case| {
+        let stats = jvm::peephole_stats(&case.stmts());
+        assert!(stats.after <= stats.before, "Peephole made {} longer", case.name);
+        common::run_jvm(&dir, &case.name, &case.stmts())
+    });
+}
+
+#[test]
+fn x86() {
+    if !common::x86_available() {
+        return;
+    }
+
+    let dir = common::scratch_dir("golden-x86");
+    check_all("x86", |case| common::run_x86(&dir, &case.name, &case.stmts()));
+}
+
+#[test]
+fn wasm() {
+    if !common::wasm_available() {
+        return;
+    }
+
+    let dir = common::scratch_dir("golden-wasm");
+    check_all("wasm", |case| common::run_wasm(&dir, &case.name, &case.stmts()));
+}
+
+/// `i32::MIN / -1` wraps to `i32::MIN` with remainder 0, as on the JVM, although the machine
+/// instructions x86 and wasm have for it trap and LLVM leaves it undefined.
+#[test]
+fn division_overflow() {
+    let source = "m = -2147483648;\n\
+                  d = -1;\n\
+                  m / d;\n\
+                  m % d;\n\
+                  m / -1;\n\
+                  m % -1;\n\
+                  7 / d;\n\
+                  d = 2;\n\
+                  m / d;\n\
+                  -7 % d";
+    let stmts = instant::parse_and_check(source).unwrap();
+    let expected = "-2147483648\n0\n-2147483648\n0\n-7\n-1073741824\n-1\n";
+
+    assert_eq!(common::format_output(&interp::run(&stmts).unwrap()), expected);
+
+    let dir = common::scratch_dir("golden-division");
+    if common::llvm_available() {
+        let code = llvm::compile(&stmts);
+        assert_eq!(common::run_llvm(&dir, "division", &code), expected);
+        let code = llvm::compile_ssa(&stmts);
+        assert_eq!(common::run_llvm(&dir, "division_ssa", &code), expected);
+    }
+    if common::jvm_available() {
+        assert_eq!(common::run_jvm(&dir, "Division", &stmts), expected);
+    }
+    if common::x86_available() {
+        assert_eq!(common::run_x86(&dir, "division", &stmts), expected);
+    }
+    if common::wasm_available() {
+        assert_eq!(common::run_wasm(&dir, "division", &stmts), expected);
+    }
+}
+
+/// Calls print, so a call may only be evaluated before the other operand when that one can't fail:
+/// nothing is printed before division by zero here, whichever operand needs more registers or stack
+#[test]
+fn call_after_failing_operand() {
+    let sources = [
+        "fn f(x) { 77; return x }\na = 1;\nz = 0;\n(a / z) + f(1)",
+        "fn f(x) { 77; return x }\na = 1;\nz = 0;\n(a / z) + (f(1) + a * (a + a))",
+    ];
+
+    let dir = common::scratch_dir("golden-call-order");
+    for (i, source) in sources.iter().enumerate() {
+        let stmts = instant::parse_and_check(source).unwrap();
+        let mut interpreter = interp::Interpreter::new();
+
+        assert_eq!(interpreter.run(&stmts), Err(interp::RuntimeError::DivisionByZero));
+        assert_eq!(interpreter.output(), &[] as &[i32]);
+
+        let name = format!("CallOrder{}", i);
+        if common::jvm_available() {
+            assert_eq!(common::run_failing(&mut common::jvm_command(&dir, &name, &stmts)), "");
+        }
+        if common::x86_available() {
+            assert_eq!(common::run_failing(&mut common::x86_command(&dir, &name, &stmts)), "");
+        }
+        if common::wasm_available() {
+            assert_eq!(common::run_failing(&mut common::wasm_command(&dir, &name, &stmts)), "");
+        }
+    }
+}
+
+/// Values printed before division by zero are kept, even with stdout going to a pipe
+#[test]
+fn output_before_failure() {
+    let stmts = instant::parse_and_check("a = 0;\n1;\n2 / a").unwrap();
+    let mut interpreter = interp::Interpreter::new();
+
+    assert_eq!(interpreter.run(&stmts), Err(interp::RuntimeError::DivisionByZero));
+    assert_eq!(interpreter.output(), &[1]);
+
+    let dir = common::scratch_dir("golden-output-before-failure");
+    if common::jvm_available() {
+        assert_eq!(common::run_failing(&mut common::jvm_command(&dir, "Failure", &stmts)), "1\n");
+    }
+    if common::x86_available() {
+        assert_eq!(common::run_failing(&mut common::x86_command(&dir, "failure", &stmts)), "1\n");
+    }
+    if common::wasm_available() {
+        assert_eq!(common::run_failing(&mut common::wasm_command(&dir, "failure", &stmts)), "1\n");
+    }
+}
+
+/// Loop and `if` bodies longer than a 16-bit jump offset reaches need `goto_w` in class files;
+/// they're in separate methods, which can't have more than 64 KiB of code each
+#[test]
+fn long_jumps() {
+    let long_body = "s = s + 123456;\n".repeat(8000);
+    let source = format!(
+        "fn f(s) {{\nif (s != 0) {{\n{}s\n}}\nreturn s\n}}\n\
+         i = 0;\ns = 0;\nwhile (i < 3) {{\n{}i = i + 1\n}}\nf(s)",
+        long_body, long_body
+    );
+    let stmts = instant::parse_and_check(&source).unwrap();
+    let expected = common::format_output(&interp::run(&stmts).unwrap());
+
+    let class = jvm::compile_class(&stmts, "LongJumps");
+    assert!(class.len() > 2 * 32768, "Methods are too short to need long jumps");
+
+    if common::jvm_available() {
+        let dir = common::scratch_dir("golden-long-jumps");
+        assert_eq!(common::run_jvm(&dir, "LongJumps", &stmts), expected);
+    }
+}
+
+/// Recursion as deep as compiled programs handle runs in the interpreter too, up to its own limit
+#[test]
+fn deep_recursion() {
+    let source = |n: i32| {
+        format!("fn rec(n) {{ if (n == 0) {{ return 0 }} return rec(n - 1) + 1 }}\nrec({})", n)
+    };
+
+    let stmts = instant::parse_and_check(&source(5000)).unwrap();
+    assert_eq!(interp::run(&stmts), Ok(vec![5000]));
+
+    let dir = common::scratch_dir("golden-recursion");
+    if common::llvm_available() {
+        assert_eq!(common::run_llvm(&dir, "rec", &llvm::compile(&stmts)), "5000\n");
+    }
+    if common::jvm_available() {
+        assert_eq!(common::run_jvm(&dir, "Rec", &stmts), "5000\n");
+    }
+    if common::x86_available() {
+        assert_eq!(common::run_x86(&dir, "rec", &stmts), "5000\n");
+    }
+    if common::wasm_available() {
+        assert_eq!(common::run_wasm(&dir, "rec", &stmts), "5000\n");
+    }
+
+    let stmts = instant::parse_and_check(&source(99_999)).unwrap();
+    assert_eq!(interp::run(&stmts), Ok(vec![99_999]));
+
+    let stmts = instant::parse_and_check(&source(100_000)).unwrap();
+    assert_eq!(interp::run(&stmts), Err(interp::RuntimeError::StackOverflow));
+
+    // Expressions around each call don't make it any deeper
+    let nested = format!("{}rec(n - 1){}", "1 + (".repeat(40), ")".repeat(40));
+    let source = format!(
+        "fn rec(n) {{ if (n == 0) {{ return 0 }} return {} }}\nrec(99000)",
+        nested
+    );
+    let stmts = instant::parse_and_check(&source).unwrap();
+    assert_eq!(interp::run(&stmts), Ok(vec![40 * 99_000]));
+}
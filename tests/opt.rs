@@ -0,0 +1,164 @@
+//! Optimisation passes on small programs, checking the rewritten AST and generated code.
+//!
+//! Expected programs are written as Instant source and compared after pretty-printing both sides.
+
+use instant::ast::Stmt;
+use instant::{interp, jvm, llvm, opt};
+
+fn print(stmts: &[Box<Stmt>]) -> String {
+    stmts
+        .iter()
+        .map(|stmt| stmt.to_string())
+        .collect::<Vec<String>>()
+        .join(";\n")
+}
+
+#[allow(clippy::vec_box)]
+fn parse(source: &str) -> Vec<Box<Stmt>> {
+    instant::parse_and_check(source).unwrap_or_else(|_| panic!("Program does not compile:\n{}", source))
+}
+
+fn assert_propagated(source: &str, expected: &str) {
+    assert_eq!(print(&opt::propagate_constants(parse(source))), print(&parse(expected)));
+}
+
+#[test]
+fn propagates_through_assignments() {
+    assert_propagated("a = 1; b = a + 2; b * a", "a = 1; b = 3; 3");
+}
+
+#[test]
+fn reassignment_replaces_known_value() {
+    assert_propagated("a = 1; a = a + 1; a; a = a * 5; a", "a = 1; a = 2; 2; a = 10; 10");
+}
+
+#[test]
+fn unknown_value_stops_propagation() {
+    assert_propagated(
+        "n = 0; while (n < 3) { n = n + 1 }; a = 4; a = n; a + 1",
+        "n = 0; while (n < 3) { n = n + 1 }; a = 4; a = n; a + 1",
+    );
+}
+
+#[test]
+fn if_merges_equal_constants_only() {
+    assert_propagated(
+        "n = 0; while (n < 3) { n = n + 1 };\n\
+         a = 1; b = 1; c = 7;\n\
+         if (n < 2) { a = 5; b = 2 } else { a = 5; c = 3 }\n\
+         a + b + c",
+        "n = 0; while (n < 3) { n = n + 1 };\n\
+         a = 1; b = 1; c = 7;\n\
+         if (n < 2) { a = 5; b = 2 } else { a = 5; c = 3 }\n\
+         5 + b + c",
+    );
+}
+
+#[test]
+fn if_branches_see_values_from_before() {
+    assert_propagated(
+        "a = 2; if (a < 3) { b = a * 4 } else { b = a }; b",
+        "a = 2; if (2 < 3) { b = 8 } else { b = 2 }; b",
+    );
+}
+
+#[test]
+fn while_invalidates_variables_assigned_in_body() {
+    assert_propagated(
+        "i = 0; k = 5; s = 1; while (i < k) { s = s * 2; i = i + k / 5 }; i + k + s",
+        "i = 0; k = 5; s = 1; while (i < 5) { s = s * 2; i = i + 1 }; i + 5 + s",
+    );
+}
+
+#[test]
+fn function_body_starts_with_nothing_known() {
+    assert_propagated(
+        "fn f(x) { y = 3; return x + y }; x = 1; f(x)",
+        "fn f(x) { y = 3; return x + 3 }; x = 1; f(1)",
+    );
+}
+
+#[test]
+fn identities_simplify_unknown_operands() {
+    assert_propagated(
+        "fn f(x) { a = x * 1; b = 0 + x; c = x - x; d = x / 1; e = 1 * (x - 0); return a + 0 }; f(2)",
+        "fn f(x) { a = x; b = x; c = 0; d = x; e = x; return a }; f(2)",
+    );
+}
+
+#[test]
+fn multiplication_by_zero_keeps_operand_which_may_fail() {
+    assert_propagated(
+        "fn f(x, z) { a = x * 0; b = (x / z) * 0; c = 0 * f(x, 1); d = x / z - x / z; return a };\n\
+         f(1, 2)",
+        "fn f(x, z) { a = 0; b = x / z * 0; c = 0 * f(x, 1); d = x / z - x / z; return 0 };\n\
+         f(1, 2)",
+    );
+}
+
+#[test]
+fn constant_division_by_zero_is_not_folded() {
+    assert_propagated("a = 7 / 0; b = 5 % (3 - 3); a", "a = 7 / 0; b = 5 % 0; a");
+}
+
+#[test]
+fn folding_wraps_on_overflow() {
+    assert_propagated(
+        "a = 2147483647 + 1; b = -2147483648 * -1; c = -2147483648 / -1;\n\
+         d = 1 << 33; e = -a; a + d",
+        "a = -2147483648; b = -2147483648; c = -2147483648;\n\
+         d = 2; e = -2147483648; -2147483646",
+    );
+}
+
+#[test]
+fn backends_emit_propagated_constants() {
+    let (stmts, _) = opt::optimize(parse("a = 6; b = a * 7; b"));
+
+    let ll = llvm::compile(&stmts);
+    assert!(ll.contains("call void @printInt(i32 42)"), "{}", ll);
+    assert!(!ll.contains("alloca"), "{}", ll);
+
+    let j = jvm::compile(&stmts, "Test");
+    assert!(j.contains("bipush 42"), "{}", j);
+    assert!(!j.contains("istore"), "{}", j);
+}
+
+#[test]
+fn dead_stores_are_counted_and_output_kept() {
+    let source = "a = 1;\n\
+                  a = 2;\n\
+                  b = a * 3;\n\
+                  unused = b + 1;\n\
+                  z = 0;\n\
+                  failing = 7 / z;\n\
+                  i = 0;\n\
+                  while (i < 3) { t = i; i = i + 1 }\n\
+                  b";
+    let stmts = parse(source);
+
+    let (eliminated, removed) = opt::eliminate_dead_stores(parse(source));
+    assert_eq!(removed, 3); // `a = 1`, `unused = ...` and `t = i`
+    assert_eq!(
+        print(&eliminated),
+        print(&parse(
+            "a = 2; b = a * 3; z = 0; failing = 7 / z; i = 0; while (i < 3) { i = i + 1 } b"
+        ))
+    );
+
+    let (optimized, removed) = opt::optimize(parse(source));
+    assert_eq!(removed, 6); // also `a = 2`, `b = ...` and `z = 0` once their values are propagated
+    assert_eq!(interp::run(&optimized), interp::run(&stmts));
+}
+
+#[test]
+fn peephole_stats_count_instructions_of_all_methods() {
+    let stmts = parse("fn f(a) { if (a < 1) { return 0 } return a * 2 }\nx = 5;\nx = x + 1;\nf(x)");
+    let stats = jvm::peephole_stats(&stmts);
+
+    // Instructions are indented, labels aren't; `<init>` and `return` of main are not counted
+    let j = jvm::compile(&stmts, "Test");
+    let emitted = j.lines().filter(|line| line.starts_with('\t')).count();
+    assert_eq!(stats.after, emitted - 3 - 1);
+    assert!(stats.after < stats.before, "{:?}", stats);
+}